@@ -0,0 +1,49 @@
+/*
+ * This file contains template code.
+ * Lists every `src/bin/<year>-<day>.rs` for `src/days.rs`, so a day made by `cargo prepare`
+ * runs with `cargo solve` and `cargo all` without registering it by hand.
+ */
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Year and day of a solution file, `None` for the other binaries.
+fn parse_name(path: &Path) -> Option<(u32, u8)> {
+    if path.extension()? != "rs" {
+        return None;
+    }
+    let name = path.file_stem()?.to_str()?;
+    let (year, day) = name.split_once('-')?;
+    let (year, day) = (year.parse().ok()?, day.parse().ok()?);
+    // only the names `cargo prepare` gives, like `2022-07`
+    (name == format!("{year}-{day:02}")).then_some((year, day))
+}
+
+fn main() {
+    let bin = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap())
+        .join("src")
+        .join("bin");
+    println!("cargo:rerun-if-changed={}", bin.display());
+
+    let mut days: Vec<(u32, u8, PathBuf)> = fs::read_dir(&bin)
+        .unwrap()
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let (year, day) = parse_name(&path)?;
+            Some((year, day, path))
+        })
+        .collect();
+    days.sort();
+
+    let mut registry = String::from("days! {\n");
+    for (year, day, path) in days {
+        registry += &format!(
+            "    y{year}_day{day:02} = {:?} => ({year}, {day}, Day{day:02}),\n",
+            path.display().to_string()
+        );
+    }
+    registry += "}\n";
+
+    let out = PathBuf::from(env::var("OUT_DIR").unwrap()).join("days.rs");
+    fs::write(out, registry).unwrap();
+}
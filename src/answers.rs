@@ -0,0 +1,196 @@
+/*
+ * This file contains template code.
+ * Pinned answers from `answers.toml`, checked by `cargo solve` and `cargo all`.
+ */
+use crate::{Error, Part, Report};
+use hashbrown::HashMap;
+use std::{fs, io};
+use toml::Value;
+
+pub const FILE: &str = "answers.toml";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Check {
+    /// no answer pinned for this part
+    Unpinned,
+    Correct,
+    Wrong {
+        expected: String,
+    },
+}
+
+impl Check {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Check::Unpinned => "unpinned",
+            Check::Correct => "correct",
+            Check::Wrong { .. } => "wrong",
+        }
+    }
+
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Check::Unpinned => "",
+            Check::Correct => "✅",
+            Check::Wrong { .. } => "❌",
+        }
+    }
+}
+
+/// Answers of one year keyed by day and part, e.g.
+/// ```toml
+/// [2022.01]
+/// part_one = 24000
+/// part_two = "45000"
+/// ```
+/// Days outside of a year table, like `[01]`, belong to [`crate::DEFAULT_YEAR`].
+#[derive(Debug, Default)]
+pub struct Answers(HashMap<(u8, u8), String>);
+
+impl Answers {
+    pub fn parse(s: &str, year: u32) -> Result<Self, String> {
+        let table = s.parse::<Value>().map_err(|e| e.to_string())?;
+        let table = table.as_table().ok_or("expected a table")?;
+
+        let mut days = Vec::new();
+        for (key, value) in table {
+            match key.parse::<u32>() {
+                // a day is at most two digits, so four are a year
+                Ok(x) if key.len() == 4 => {
+                    if x != year {
+                        continue;
+                    }
+                    let value = value
+                        .as_table()
+                        .ok_or(format!("`{key}` should be a table of days"))?;
+                    days.extend(
+                        value
+                            .iter()
+                            .map(|(day, parts)| (format!("{key}.{day}"), day, parts)),
+                    );
+                }
+                _ if year == crate::DEFAULT_YEAR => days.push((key.clone(), key, value)),
+                _ => {}
+            }
+        }
+
+        let mut answers = HashMap::new();
+        for (name, day, parts) in days {
+            let day_num = day
+                .parse::<u8>()
+                .map_err(|_| format!("`{name}` is not a day"))?;
+            let parts = parts
+                .as_table()
+                .ok_or(format!("`{name}` should be a table of parts"))?;
+            for (part, answer) in parts {
+                let part_num = match part.as_str() {
+                    "part_one" => 1,
+                    "part_two" => 2,
+                    _ => return Err(format!("unknown part `{part}` in day `{name}`")),
+                };
+                let answer = match answer {
+                    Value::String(x) => x.clone(),
+                    Value::Integer(x) => x.to_string(),
+                    _ => return Err(format!("`{name}.{part}` should be a string or integer")),
+                };
+                answers.insert((day_num, part_num), answer);
+            }
+        }
+        Ok(Answers(answers))
+    }
+
+    /// Reads the answers of `year` from `answers.toml` in the crate root; a missing file pins nothing.
+    pub fn load(year: u32) -> crate::Result<Self> {
+        let path = crate::root().join(FILE);
+        match fs::read_to_string(&path) {
+            Ok(s) => Answers::parse(&s, year).map_err(|message| Error::Malformed { path, message }),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(Error::io(path, e)),
+        }
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.0.get(&(day, part)).map(String::as_str)
+    }
+
+    /// A pinned part that is not solved counts as wrong.
+    pub fn check(&self, day: u8, part: &Part) -> Check {
+        match self.get(day, part.part) {
+            None => Check::Unpinned,
+            Some(expected) if part.status.answer() == Some(expected) => Check::Correct,
+            Some(expected) => Check::Wrong {
+                expected: expected.to_string(),
+            },
+        }
+    }
+
+    pub fn mismatches(&self, day: u8, report: &Report) -> usize {
+        report
+            .parts
+            .iter()
+            .filter(|x| matches!(self.check(day, x), Check::Wrong { .. }))
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Status;
+    use std::time::Duration;
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse(
+            "[01]\npart_one = 24000\npart_two = \"45000\"\n\n[10]\npart_two = '''\n##..\n#...'''\n",
+            2022,
+        )
+        .unwrap();
+        assert_eq!(answers.get(1, 1), Some("24000"));
+        assert_eq!(answers.get(1, 2), Some("45000"));
+        assert_eq!(answers.get(10, 1), None);
+        assert_eq!(answers.get(10, 2), Some("##..\n#..."));
+
+        assert!(Answers::parse("[01]\npart_three = 1", 2022).is_err());
+        assert!(Answers::parse("[first]\npart_one = 1", 2022).is_err());
+        assert!(Answers::parse("[01]\npart_one = 1.5", 2022).is_err());
+    }
+
+    #[test]
+    fn test_years() {
+        let file = "[01]\npart_one = 1\n\n[2022.02]\npart_one = 2\n\n[2021.01]\npart_one = 3";
+        let answers = Answers::parse(file, 2022).unwrap();
+        assert_eq!(answers.get(1, 1), Some("1"));
+        assert_eq!(answers.get(2, 1), Some("2"));
+        let answers = Answers::parse(file, 2021).unwrap();
+        assert_eq!(answers.get(1, 1), Some("3"));
+        assert_eq!(answers.get(2, 1), None);
+        assert!(Answers::parse("[2021.first]\npart_one = 1", 2021).is_err());
+    }
+
+    #[test]
+    fn test_check() {
+        let answers = Answers::parse("[01]\npart_one = 24000\npart_two = 45000", 2022).unwrap();
+        let part = |part, status| Part::new(part, status, Duration::ZERO);
+
+        let report = Report {
+            parse: Duration::ZERO,
+            parse_stats: None,
+            parts: [
+                part(1, Status::Solved("24000".to_string())),
+                part(2, Status::Solved("45001".to_string())),
+            ],
+        };
+        assert_eq!(answers.check(1, &report.parts[0]), Check::Correct);
+        assert_eq!(
+            answers.check(1, &report.parts[1]),
+            Check::Wrong {
+                expected: "45000".to_string()
+            }
+        );
+        assert_eq!(answers.check(2, &report.parts[0]), Check::Unpinned);
+        assert_eq!(answers.check(1, &part(1, Status::Unsolved)).name(), "wrong");
+        assert_eq!(answers.mismatches(1, &report), 1);
+        assert_eq!(answers.mismatches(2, &report), 0);
+    }
+}
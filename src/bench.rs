@@ -0,0 +1,157 @@
+/*
+ * This file contains template code.
+ * Repeated timing of solutions for `--bench`.
+ */
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone)]
+pub struct Config {
+    /// untimed calls before sampling starts
+    pub warmup: u32,
+    /// samples taken before checking whether the result is stable
+    pub min_samples: u32,
+    pub max_samples: u32,
+    /// sampling stops once the standard error is below this fraction of the mean
+    pub tolerance: f64,
+    /// time limit per benchmarked function, so slow days don't take forever
+    pub budget: Duration,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            warmup: 3,
+            min_samples: 10,
+            max_samples: 1000,
+            tolerance: 0.01,
+            budget: Duration::from_secs(5),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stats {
+    pub samples: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub p95: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let n = sorted.len();
+        let nanos = sorted
+            .iter()
+            .map(|x| x.as_nanos() as f64)
+            .collect::<Vec<_>>();
+        let mean = nanos.iter().sum::<f64>() / n as f64;
+        let variance = match n {
+            1 => 0_f64,
+            _ => nanos.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1) as f64,
+        };
+        let median = match n % 2 {
+            0 => (sorted[n / 2 - 1] + sorted[n / 2]) / 2,
+            _ => sorted[n / 2],
+        };
+        let p95 = sorted[((n as f64 * 0.95).ceil() as usize).clamp(1, n) - 1];
+
+        Stats {
+            samples: n,
+            min: sorted[0],
+            median,
+            mean: Duration::from_nanos(mean as u64),
+            p95,
+            stddev: Duration::from_nanos(variance.sqrt() as u64),
+        }
+    }
+
+    fn is_stable(&self, tolerance: f64) -> bool {
+        let standard_error = self.stddev.as_secs_f64() / (self.samples as f64).sqrt();
+        standard_error < tolerance * self.mean.as_secs_f64()
+    }
+}
+
+pub fn sample(config: &Config, mut f: impl FnMut()) -> Stats {
+    let started = Instant::now();
+
+    for _ in 0..config.warmup {
+        if started.elapsed() >= config.budget {
+            break;
+        }
+        f();
+    }
+
+    let mut samples = Vec::new();
+    loop {
+        let timer = Instant::now();
+        f();
+        samples.push(timer.elapsed());
+
+        let n = samples.len() as u32;
+        if n >= config.max_samples || started.elapsed() >= config.budget {
+            break;
+        }
+        if n >= config.min_samples
+            && n.is_multiple_of(config.min_samples)
+            && Stats::from_samples(&samples).is_stable(config.tolerance)
+        {
+            break;
+        }
+    }
+
+    Stats::from_samples(&samples)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn micros(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|x| Duration::from_micros(*x)).collect()
+    }
+
+    #[test]
+    fn test_from_samples() {
+        let stats = Stats::from_samples(&micros(&[5, 1, 4, 2, 3]));
+        assert_eq!(stats.samples, 5);
+        assert_eq!(stats.min, Duration::from_micros(1));
+        assert_eq!(stats.median, Duration::from_micros(3));
+        assert_eq!(stats.mean, Duration::from_micros(3));
+        assert_eq!(stats.p95, Duration::from_micros(5));
+        // sample standard deviation of 1..=5 is sqrt(2.5)
+        assert_eq!(stats.stddev, Duration::from_nanos(1581));
+
+        let stats = Stats::from_samples(&micros(&[4, 1, 3, 2]));
+        assert_eq!(stats.median, Duration::from_nanos(2500));
+
+        let stats = Stats::from_samples(&micros(&[7]));
+        assert_eq!(stats.p95, Duration::from_micros(7));
+        assert_eq!(stats.stddev, Duration::ZERO);
+    }
+
+    #[test]
+    fn test_sample_stops() {
+        let config = Config {
+            warmup: 1,
+            min_samples: 5,
+            max_samples: 20,
+            tolerance: 0_f64,
+            budget: Duration::from_secs(60),
+        };
+        let mut calls = 0;
+        let stats = sample(&config, || calls += 1);
+        assert_eq!(stats.samples, 20);
+        assert_eq!(calls, 21);
+
+        let config = Config {
+            budget: Duration::ZERO,
+            ..config
+        };
+        assert_eq!(sample(&config, || ()).samples, 1);
+    }
+}
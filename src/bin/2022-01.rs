@@ -0,0 +1,42 @@
+use aoc::Solution;
+use elves::parsers::vec_lines;
+use itertools::Itertools;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Parsed = Vec<u32>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Self::Parsed {
+        input
+            .trim()
+            .split("\n\n")
+            .map(|x| vec_lines::<u32>(x).iter().sum())
+            .collect()
+    }
+    fn part_one(calories: &Self::Parsed) -> Option<u32> {
+        calories.iter().max().copied()
+    }
+    fn part_two(calories: &Self::Parsed) -> Option<u32> {
+        Some(calories.iter().sorted_by(|a, b| b.cmp(a)).take(3).sum())
+    }
+}
+fn main() {
+    aoc::solve!(2022, 1, Day01);
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_part_one() {
+        let input = aoc::read_file("test_inputs", Some(2022), 1).unwrap();
+        assert_eq!(Day01::part_one(&Day01::parse(&input)), Some(24000));
+    }
+    #[test]
+    fn test_part_two() {
+        let input = aoc::read_file("test_inputs", Some(2022), 1).unwrap();
+        assert_eq!(Day01::part_two(&Day01::parse(&input)), Some(45000));
+    }
+}
@@ -1,3 +1,4 @@
+use aoc::Solution;
 use itertools::Itertools;
 use Outcome::*;
 use Shape::*;
@@ -86,42 +87,66 @@ impl From<&str> for Outcome {
     }
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let mut score = 0;
-    for line in input.trim().split('\n') {
-        let (in_oponnent, in_me) = line.split(' ').tuple_windows().next().unwrap();
-        let oponnent = Shape::from(in_oponnent);
-        let me = Shape::from(in_me);
-        score += Outcome::play(&me, &oponnent).value() + me.value();
-    }
-    Some(score)
+// second column is read as a shape in part one and as an outcome in part two
+pub struct Round {
+    oponnent: Shape,
+    me: Shape,
+    order: Outcome,
 }
-pub fn part_two(input: &str) -> Option<u32> {
-    let mut score = 0;
-    for line in input.trim().split('\n') {
-        let (in_oponnent, in_order) = line.split(' ').tuple_windows().next().unwrap();
-        let oponnent = Shape::from(in_oponnent);
-        let order = Outcome::from(in_order);
-        score += order.value() + order.get_shape(&oponnent).value();
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Parsed = Vec<Round>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Self::Parsed {
+        input
+            .trim()
+            .split('\n')
+            .map(|line| {
+                let (in_oponnent, in_second) = line.split(' ').tuple_windows().next().unwrap();
+                Round {
+                    oponnent: Shape::from(in_oponnent),
+                    me: Shape::from(in_second),
+                    order: Outcome::from(in_second),
+                }
+            })
+            .collect()
+    }
+    fn part_one(rounds: &Self::Parsed) -> Option<u32> {
+        let mut score = 0;
+        for Round { oponnent, me, .. } in rounds.iter() {
+            score += Outcome::play(me, oponnent).value() + me.value();
+        }
+        Some(score)
+    }
+    fn part_two(rounds: &Self::Parsed) -> Option<u32> {
+        let mut score = 0;
+        for Round {
+            oponnent, order, ..
+        } in rounds.iter()
+        {
+            score += order.value() + order.get_shape(oponnent).value();
+        }
+        Some(score)
     }
-    Some(score)
 }
 fn main() {
-    let input = &aoc::read_file("inputs", 2);
-    aoc::solve!(1, part_one, input);
-    aoc::solve!(2, part_two, input);
+    aoc::solve!(2022, 2, Day02);
 }
 #[cfg(test)]
 mod tests {
     use super::*;
     #[test]
     fn test_part_one() {
-        let input = aoc::read_file("test_inputs", 2);
-        assert_eq!(part_one(&input), Some(15));
+        let input = aoc::read_file("test_inputs", Some(2022), 2).unwrap();
+        assert_eq!(Day02::part_one(&Day02::parse(&input)), Some(15));
     }
     #[test]
     fn test_part_two() {
-        let input = aoc::read_file("test_inputs", 2);
-        assert_eq!(part_two(&input), Some(12));
+        let input = aoc::read_file("test_inputs", Some(2022), 2).unwrap();
+        assert_eq!(Day02::part_two(&Day02::parse(&input)), Some(12));
     }
 }
@@ -0,0 +1,81 @@
+use aoc::Solution;
+use hashbrown::{HashMap, HashSet};
+use lazy_static::lazy_static;
+
+lazy_static! {
+    static ref PRIORITY: HashMap<char, u32> = ('a'..='z')
+        .chain('A'..='Z')
+        .enumerate()
+        .map(|(i, x)| (x, (i + 1) as u32))
+        .collect();
+}
+
+fn priority(c: char) -> u32 {
+    PRIORITY[&c]
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Parsed = Vec<String>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Self::Parsed {
+        input.trim().split('\n').map(String::from).collect()
+    }
+    fn part_one(rucksacks: &Self::Parsed) -> Option<u32> {
+        let mut score = 0;
+        for rucksack in rucksacks.iter() {
+            let compartment_size = rucksack.len() / 2;
+            let set: HashSet<char> = rucksack[..compartment_size].chars().collect();
+            for item in rucksack[compartment_size..].chars() {
+                if set.contains(&item) {
+                    score += priority(item);
+                    break;
+                }
+            }
+        }
+        Some(score)
+    }
+
+    fn part_two(rucksacks: &Self::Parsed) -> Option<u32> {
+        let mut score = 0;
+        let mut map = HashMap::with_capacity(26 * 2);
+        let mut set = HashSet::with_capacity(26 * 2);
+
+        for group in rucksacks.chunks(3) {
+            map.clear();
+            for rucksack in group.iter() {
+                set.clear();
+                for c in rucksack.chars() {
+                    if !set.contains(&c) {
+                        *map.entry(c).or_insert(0) += 1;
+                        set.insert(c);
+                    };
+                }
+            }
+            map.retain(|_, &mut v| v == 3);
+            let item = map.keys().next().unwrap();
+            score += priority(*item);
+        }
+        Some(score)
+    }
+}
+fn main() {
+    aoc::solve!(2022, 3, Day03);
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_part_one() {
+        let input = aoc::read_file("test_inputs", Some(2022), 3).unwrap();
+        assert_eq!(Day03::part_one(&Day03::parse(&input)), Some(157));
+    }
+    #[test]
+    fn test_part_two() {
+        let input = aoc::read_file("test_inputs", Some(2022), 3).unwrap();
+        assert_eq!(Day03::part_two(&Day03::parse(&input)), Some(70));
+    }
+}
@@ -0,0 +1,53 @@
+use aoc::helpers::ranges::RangeSet;
+use aoc::Solution;
+use itertools::Itertools;
+
+fn read_line(line: &str) -> (RangeSet<u32>, RangeSet<u32>) {
+    line.split(',')
+        .map(|x| {
+            let (start, end) = x.split_once('-').unwrap();
+            RangeSet::from(start.parse().unwrap()..=end.parse().unwrap())
+        })
+        .next_tuple()
+        .unwrap()
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Parsed = Vec<(RangeSet<u32>, RangeSet<u32>)>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Self::Parsed {
+        input.trim().split('\n').map(read_line).collect()
+    }
+    fn part_one(pairs: &Self::Parsed) -> Option<usize> {
+        let score = pairs
+            .iter()
+            .filter(|(a, b)| a.is_superset(b) || b.is_superset(a))
+            .count();
+        Some(score)
+    }
+
+    fn part_two(pairs: &Self::Parsed) -> Option<usize> {
+        Some(pairs.iter().filter(|(a, b)| a.overlaps(b)).count())
+    }
+}
+fn main() {
+    aoc::solve!(2022, 4, Day04);
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_part_one() {
+        let input = aoc::read_file("test_inputs", Some(2022), 4).unwrap();
+        assert_eq!(Day04::part_one(&Day04::parse(&input)), Some(2));
+    }
+    #[test]
+    fn test_part_two() {
+        let input = aoc::read_file("test_inputs", Some(2022), 4).unwrap();
+        assert_eq!(Day04::part_two(&Day04::parse(&input)), Some(4));
+    }
+}
@@ -0,0 +1,113 @@
+use aoc::Solution;
+use itertools::Itertools;
+use lazy_static::lazy_static;
+use regex::Regex;
+
+lazy_static! {
+    static ref RE: Regex = Regex::new(r#"move (\d+) from (\d+) to (\d+)"#).unwrap();
+}
+
+pub struct Crane {
+    towers: Vec<Vec<char>>,
+    instructions: Vec<(usize, usize, usize)>,
+}
+
+fn parse_towers(text_towers: &str) -> Vec<Vec<char>> {
+    let mut rows = text_towers.split('\n').rev();
+    let num_towers = rows.next().unwrap().trim().split("   ").count();
+    let mut towers = Vec::new();
+    for _ in 0..num_towers {
+        towers.push(Vec::new());
+    }
+
+    for row in rows {
+        for (i, c) in row.chars().skip(1).step_by(4).enumerate() {
+            if c != ' ' {
+                towers[i].push(c);
+            }
+        }
+    }
+
+    towers
+}
+
+fn parse_instructions(instructions: &str) -> Vec<(usize, usize, usize)> {
+    instructions
+        .trim()
+        .split('\n')
+        .map(|line| {
+            let captures = RE.captures(line).unwrap();
+            (1..=3)
+                .map(|x| captures[x].parse().unwrap())
+                .tuple_windows()
+                .next()
+                .unwrap()
+        })
+        .collect()
+}
+
+fn move_crates(crane: &Crane, reverse: bool) -> Option<String> {
+    let mut towers = crane.towers.clone();
+
+    for &(amount, from, to) in crane.instructions.iter() {
+        let mut aux = Vec::new();
+        for _ in 0..amount {
+            let aaux = towers[from - 1].pop().unwrap();
+            aux.push(aaux);
+        }
+        if reverse {
+            aux.reverse();
+        }
+
+        towers[to - 1].append(&mut aux);
+    }
+
+    Some(String::from_iter(
+        towers.iter().map(|x| x.iter().next_back().unwrap()),
+    ))
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Parsed = Crane;
+    type PartOne = String;
+    type PartTwo = String;
+
+    fn parse(input: &str) -> Self::Parsed {
+        let (text_towers, instructions) = input.split("\n\n").next_tuple().unwrap();
+        Crane {
+            towers: parse_towers(text_towers),
+            instructions: parse_instructions(instructions),
+        }
+    }
+    fn part_one(crane: &Self::Parsed) -> Option<String> {
+        move_crates(crane, false)
+    }
+    fn part_two(crane: &Self::Parsed) -> Option<String> {
+        move_crates(crane, true)
+    }
+}
+fn main() {
+    aoc::solve!(2022, 5, Day05);
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_part_one() {
+        let input = aoc::read_file("test_inputs", Some(2022), 5).unwrap();
+        assert_eq!(
+            Day05::part_one(&Day05::parse(&input)),
+            Some("CMZ".to_string())
+        );
+    }
+    #[test]
+    fn test_part_two() {
+        let input = aoc::read_file("test_inputs", Some(2022), 5).unwrap();
+        assert_eq!(
+            Day05::part_two(&Day05::parse(&input)),
+            Some("MCD".to_string())
+        );
+    }
+}
@@ -0,0 +1,49 @@
+use aoc::Solution;
+use hashbrown::HashSet;
+
+fn marker_length(input: &str, length: usize) -> Option<u32> {
+    let mut set = HashSet::with_capacity(length + 1);
+    for i in 0..input.len() - length {
+        set.clear();
+        set.extend(input[i..i + length].chars());
+        if set.len() == length {
+            return Some(i as u32 + length as u32);
+        }
+    }
+    None
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Parsed = String;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Self::Parsed {
+        input.trim().to_string()
+    }
+    fn part_one(signal: &Self::Parsed) -> Option<u32> {
+        marker_length(signal, 4)
+    }
+    fn part_two(signal: &Self::Parsed) -> Option<u32> {
+        marker_length(signal, 14)
+    }
+}
+fn main() {
+    aoc::solve!(2022, 6, Day06);
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_part_one() {
+        let input = aoc::read_file("test_inputs", Some(2022), 6).unwrap();
+        assert_eq!(Day06::part_one(&Day06::parse(&input)), Some(10));
+    }
+    #[test]
+    fn test_part_two() {
+        let input = aoc::read_file("test_inputs", Some(2022), 6).unwrap();
+        assert_eq!(Day06::part_two(&Day06::parse(&input)), Some(29));
+    }
+}
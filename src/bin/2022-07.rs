@@ -1,8 +1,9 @@
+use aoc::Solution;
 use hashbrown::HashMap;
 use itertools::Itertools;
 
 #[derive(Debug, Default)]
-struct Dir {
+pub struct Dir {
     dirs: HashMap<String, Dir>,
     file_size: u32,
     size: Option<u32>,
@@ -118,34 +119,41 @@ fn parse_root(input: &str) -> Dir {
     root
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let mut root = parse_root(input);
-    root.calc_size();
-    Some(root.sum_lt(100000))
-}
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Parsed = Dir;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Self::Parsed {
+        let mut root = parse_root(input);
+        root.calc_size();
+        root
+    }
+    fn part_one(root: &Self::Parsed) -> Option<u32> {
+        Some(root.sum_lt(100000))
+    }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let mut root = parse_root(input);
-    root.calc_size();
-    let size = root.size.unwrap() - 40000000;
-    Some(root.find_smallest_gt(size))
+    fn part_two(root: &Self::Parsed) -> Option<u32> {
+        let size = root.size.unwrap() - 40000000;
+        Some(root.find_smallest_gt(size))
+    }
 }
 fn main() {
-    let input = &aoc::read_file("inputs", 7);
-    aoc::solve!(1, part_one, input);
-    aoc::solve!(2, part_two, input);
+    aoc::solve!(2022, 7, Day07);
 }
 #[cfg(test)]
 mod tests {
     use super::*;
     #[test]
     fn test_part_one() {
-        let input = aoc::read_file("test_inputs", 7);
-        assert_eq!(part_one(&input), Some(95437));
+        let input = aoc::read_file("test_inputs", Some(2022), 7).unwrap();
+        assert_eq!(Day07::part_one(&Day07::parse(&input)), Some(95437));
     }
     #[test]
     fn test_part_two() {
-        let input = aoc::read_file("test_inputs", 7);
-        assert_eq!(part_two(&input), Some(24933642));
+        let input = aoc::read_file("test_inputs", Some(2022), 7).unwrap();
+        assert_eq!(Day07::part_two(&Day07::parse(&input)), Some(24933642));
     }
 }
@@ -0,0 +1,68 @@
+use aoc::helpers::grid::{Grid, NEIGHBOURS_4};
+use aoc::Solution;
+
+/// Number of trees seen from `at` towards `direction`, up to and including the first one
+/// that is at least as tall.
+fn viewing_distance(trees: &Grid<u32>, at: (usize, usize), direction: (isize, isize)) -> u32 {
+    let mut count = 0;
+    for x in trees.ray(at, direction) {
+        count += 1;
+        if trees[x] >= trees[at] {
+            break;
+        }
+    }
+    count
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Parsed = Grid<u32>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Self::Parsed {
+        Grid::from_str(input.trim(), |x| x.to_digit(10).unwrap())
+    }
+    fn part_one(trees: &Self::Parsed) -> Option<u32> {
+        let count = trees
+            .positions()
+            .filter(|&at| {
+                NEIGHBOURS_4
+                    .iter()
+                    .any(|&d| trees.ray(at, d).all(|x| trees[x] < trees[at]))
+            })
+            .count();
+
+        Some(count as u32)
+    }
+
+    fn part_two(trees: &Self::Parsed) -> Option<u32> {
+        trees
+            .positions()
+            .map(|at| {
+                NEIGHBOURS_4
+                    .iter()
+                    .map(|&d| viewing_distance(trees, at, d))
+                    .product()
+            })
+            .max()
+    }
+}
+fn main() {
+    aoc::solve!(2022, 8, Day08);
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_part_one() {
+        let input = aoc::read_file("test_inputs", Some(2022), 8).unwrap();
+        assert_eq!(Day08::part_one(&Day08::parse(&input)), Some(21));
+    }
+    #[test]
+    fn test_part_two() {
+        let input = aoc::read_file("test_inputs", Some(2022), 8).unwrap();
+        assert_eq!(Day08::part_two(&Day08::parse(&input)), Some(8));
+    }
+}
@@ -0,0 +1,103 @@
+use aoc::helpers::direction::{Direction, Direction::*};
+use aoc::helpers::parser::{
+    self, lines, literal, map, number, one_of, pair, terminated, ParseError,
+};
+use aoc::helpers::point::Point2;
+use aoc::Solution;
+use hashbrown::HashSet;
+
+#[derive(Debug)]
+pub struct Move {
+    direction: Direction,
+    steps: u32,
+}
+
+fn parse_input(input: &str) -> Result<Vec<Move>, ParseError> {
+    let direction = one_of(&[("L", Left), ("R", Right), ("U", Up), ("D", Down)]);
+    let step = pair(terminated(direction, literal(" ")), number());
+    parser::parse_all(
+        lines(map(step, |(direction, steps)| Move { direction, steps })),
+        input,
+    )
+}
+
+fn move_tail(tail: &mut Point2<i32>, prev: &Point2<i32>) {
+    if tail.chebyshev(*prev) > 1 {
+        *tail += (*prev - *tail).signum();
+    }
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Parsed = Vec<Move>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Self::Parsed {
+        aoc::parse_or_panic(Self::try_parse(input))
+    }
+    fn try_parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+    fn part_one(moves: &Self::Parsed) -> Option<u32> {
+        let mut head = Point2::default();
+        let mut tail = Point2::default();
+        let mut tail_trail = HashSet::new();
+        tail_trail.insert(tail);
+
+        for m in moves.iter() {
+            for _ in 0..m.steps {
+                head += m.direction.offset();
+                move_tail(&mut tail, &head);
+                tail_trail.insert(tail);
+            }
+        }
+
+        Some(tail_trail.len() as u32)
+    }
+    fn part_two(moves: &Self::Parsed) -> Option<u32> {
+        let mut head = Point2::default();
+        let mut tail = [Point2::default(); 9];
+        let mut tail_trail = HashSet::new();
+        tail_trail.insert(tail[8]);
+
+        for m in moves.iter() {
+            for _ in 0..m.steps {
+                head += m.direction.offset();
+                move_tail(&mut tail[0], &head);
+                for tail_index in 1..=8 {
+                    let prev = tail[tail_index - 1];
+                    move_tail(&mut tail[tail_index], &prev);
+                }
+                tail_trail.insert(tail[8]);
+            }
+        }
+
+        Some(tail_trail.len() as u32)
+    }
+}
+fn main() {
+    aoc::solve!(2022, 9, Day09);
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_part_one() {
+        let input = aoc::read_file("test_inputs", Some(2022), 9).unwrap();
+        assert_eq!(Day09::part_one(&Day09::parse(&input)), Some(88));
+    }
+    #[test]
+    fn test_part_two() {
+        let input = aoc::read_file("test_inputs", Some(2022), 9).unwrap();
+        assert_eq!(Day09::part_two(&Day09::parse(&input)), Some(36));
+    }
+    #[test]
+    fn test_invalid_input() {
+        let error = Day09::try_parse("R 4\nX 2\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.found, "\"X\"");
+        assert!(Day09::try_parse("R 4\nU").is_err());
+    }
+}
@@ -0,0 +1,87 @@
+use aoc::Solution;
+use itertools::Itertools;
+
+// register value during each cycle, starting with the first one
+fn parse_history(input: &str) -> Vec<i32> {
+    let mut value = 1;
+    let mut register_history = vec![];
+    for line in input.lines() {
+        match &line[..4] {
+            "noop" => register_history.push(value),
+            "addx" => {
+                register_history.extend(vec![value, value]);
+                value += line.split(' ').nth(1).unwrap().parse::<i32>().unwrap();
+            }
+            _ => unreachable!(),
+        }
+    }
+    register_history
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Parsed = Vec<i32>;
+    type PartOne = u32;
+    type PartTwo = String;
+
+    fn parse(input: &str) -> Self::Parsed {
+        parse_history(input)
+    }
+    fn part_one(register_history: &Self::Parsed) -> Option<u32> {
+        let interesting: Vec<usize> = vec![20, 60, 100, 140, 180, 220];
+        Some(
+            interesting
+                .iter()
+                .map(|x| *x as i32 * register_history[*x - 1])
+                .sum::<i32>() as u32,
+        )
+    }
+    fn part_two(register_history: &Self::Parsed) -> Option<String> {
+        Some(
+            (0..6)
+                .map(|x| {
+                    (x * 40..(x + 1) * 40)
+                        .map(|y| {
+                            if ((y % 40) as i32 - register_history[y]).abs() <= 1 {
+                                '\u{2588}'
+                            } else {
+                                ' '
+                            }
+                        })
+                        .join("")
+                })
+                .join("\n"),
+        )
+    }
+}
+fn main() {
+    aoc::solve!(2022, 10, Day10);
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_part_one() {
+        let input = aoc::read_file("test_inputs", Some(2022), 10).unwrap();
+        assert_eq!(Day10::part_one(&Day10::parse(&input)), Some(13140));
+    }
+    #[test]
+    fn test_part_two() {
+        let input = aoc::read_file("test_inputs", Some(2022), 10).unwrap();
+        assert_eq!(
+            Day10::part_two(&Day10::parse(&input)),
+            Some(
+                "\
+                    ██  ██  ██  ██  ██  ██  ██  ██  ██  ██  \n\
+                    ███   ███   ███   ███   ███   ███   ███ \n\
+                    ████    ████    ████    ████    ████    \n\
+                    █████     █████     █████     █████     \n\
+                    ██████      ██████      ██████      ████\n\
+                    ███████       ███████       ███████     \
+                    "
+                .to_string()
+            )
+        );
+    }
+}
@@ -0,0 +1,153 @@
+use aoc::helpers::parser::{
+    self, key_value, literal, map, newline, number, one_of, or, pair, preceded, separated,
+    terminated, Input, ParseError, ParseResult, Parser,
+};
+use aoc::Solution;
+
+#[derive(Debug, Clone, Copy)]
+pub enum Operand {
+    Old,
+    Value(i64),
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Operation {
+    Add(Operand),
+    Mul(Operand),
+}
+
+impl Operation {
+    fn apply(self, old: i64) -> i64 {
+        let value = |x| match x {
+            Operand::Old => old,
+            Operand::Value(x) => x,
+        };
+        match self {
+            Operation::Add(x) => old + value(x),
+            Operation::Mul(x) => old * value(x),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    items: Vec<i64>,
+    operation: Operation,
+    test: i64,
+    next: (usize, usize),
+    inspections: i64,
+}
+
+/// One block of the input, the monkeys are listed in order so the number is skipped.
+fn monkey(input: Input) -> ParseResult<Monkey> {
+    let header = terminated(
+        preceded(literal("Monkey "), number::<usize>()),
+        literal(":"),
+    );
+    let items = key_value("Starting items", separated(number(), ", "));
+    let operand = or(
+        map(literal("old"), |_| Operand::Old),
+        map(number(), Operand::Value),
+    );
+    let operator = one_of(&[
+        (" + ", Operation::Add as fn(_) -> _),
+        (" * ", Operation::Mul),
+    ]);
+    let operation = key_value(
+        "Operation",
+        preceded(
+            literal("new = old"),
+            map(pair(operator, operand), |(f, x)| f(x)),
+        ),
+    );
+    let test = key_value("Test", preceded(literal("divisible by "), number()));
+    let if_true = key_value("If true", preceded(literal("throw to monkey "), number()));
+    let if_false = key_value("If false", preceded(literal("throw to monkey "), number()));
+
+    let (_, input) = terminated(header, newline()).parse(input)?;
+    let (items, input) = terminated(items, newline()).parse(input)?;
+    let (operation, input) = terminated(operation, newline()).parse(input)?;
+    let (test, input) = terminated(test, newline()).parse(input)?;
+    let (next, input) = pair(terminated(if_true, newline()), if_false).parse(input)?;
+    let monkey = Monkey {
+        items,
+        operation,
+        test,
+        next,
+        inspections: 0,
+    };
+    Ok((monkey, input))
+}
+
+fn monkey_rounds(monkeys: &[Monkey], rounds: usize, stress_relief: bool) -> Option<i64> {
+    let mut monkeys = monkeys.to_vec();
+    let mut new_items = vec![Vec::new(); monkeys.len()];
+    let constraint: i64 = monkeys.iter().fold(1, |acc, x| acc * x.test);
+
+    for _ in 0..rounds {
+        for (idm, monke) in monkeys.iter_mut().enumerate() {
+            monke.items.append(&mut new_items[idm]);
+            for item in monke.items.drain(..) {
+                let mut new = monke.operation.apply(item);
+
+                if stress_relief {
+                    new /= 3;
+                }
+                new %= constraint;
+                monke.inspections += 1;
+                if new % monke.test == 0 {
+                    new_items[monke.next.0].push(new);
+                } else {
+                    new_items[monke.next.1].push(new);
+                }
+            }
+        }
+    }
+    monkeys.sort_by_key(|m| 0 - m.inspections);
+    Some(monkeys[0].inspections * monkeys[1].inspections)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Parsed = Vec<Monkey>;
+    type PartOne = i64;
+    type PartTwo = i64;
+
+    fn parse(input: &str) -> Self::Parsed {
+        aoc::parse_or_panic(Self::try_parse(input))
+    }
+    fn try_parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parser::parse_all(parser::blocks(monkey), input)
+    }
+    fn part_one(monkeys: &Self::Parsed) -> Option<i64> {
+        monkey_rounds(monkeys, 20, true)
+    }
+    fn part_two(monkeys: &Self::Parsed) -> Option<i64> {
+        monkey_rounds(monkeys, 10000, false)
+    }
+}
+fn main() {
+    aoc::solve!(2022, 11, Day11);
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_part_one() {
+        let input = aoc::read_file("test_inputs", Some(2022), 11).unwrap();
+        assert_eq!(Day11::part_one(&Day11::parse(&input)), Some(10605));
+    }
+    #[test]
+    fn test_part_two() {
+        let input = aoc::read_file("test_inputs", Some(2022), 11).unwrap();
+        assert_eq!(Day11::part_two(&Day11::parse(&input)), Some(2713310158));
+    }
+    #[test]
+    fn test_truncated_input() {
+        let input = aoc::read_file("test_inputs", Some(2022), 11).unwrap();
+        let error = Day11::try_parse(&input[..35]).unwrap_err();
+        assert_eq!((error.line, error.column), (3, 1));
+        assert_eq!(error.found, "end of input");
+    }
+}
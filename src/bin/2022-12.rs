@@ -0,0 +1,74 @@
+use aoc::helpers::grid::Grid;
+use aoc::helpers::search::bfs;
+use aoc::Solution;
+
+pub struct Input {
+    grid: Grid<u8>,
+    start: (usize, usize),
+    end: (usize, usize),
+}
+
+fn parse_input(input: &str) -> Input {
+    let grid = Grid::from_str(input.trim(), |c| c);
+    let start = grid.position(|&x| x == 'S').unwrap();
+    let end = grid.position(|&x| x == 'E').unwrap();
+
+    let grid = grid.map(|&x| match x {
+        'S' => b'a',
+        'E' => b'z',
+        x => x as u8,
+    });
+
+    Input { grid, start, end }
+}
+
+/// Steps from the closest of `starts` to the end, climbing at most one level per step.
+fn climb(input: &Input, starts: impl IntoIterator<Item = (usize, usize)>) -> Option<u32> {
+    let Input { grid, end, .. } = input;
+    let path = bfs(
+        starts,
+        |&at| {
+            grid.neighbours_4(at)
+                .filter(move |&x| grid[x] <= grid[at] + 1)
+        },
+        |at| at == end,
+    )?;
+    Some(path.cost as u32)
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Parsed = Input;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Self::Parsed {
+        parse_input(input)
+    }
+    fn part_one(input: &Self::Parsed) -> Option<u32> {
+        climb(input, [input.start])
+    }
+
+    fn part_two(input: &Self::Parsed) -> Option<u32> {
+        let lowest = input.grid.iter().filter(|(_, &x)| x == b'a');
+        climb(input, lowest.map(|(at, _)| at))
+    }
+}
+fn main() {
+    aoc::solve!(2022, 12, Day12);
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_part_one() {
+        let input = aoc::read_file("test_inputs", Some(2022), 12).unwrap();
+        assert_eq!(Day12::part_one(&Day12::parse(&input)), Some(31));
+    }
+    #[test]
+    fn test_part_two() {
+        let input = aoc::read_file("test_inputs", Some(2022), 12).unwrap();
+        assert_eq!(Day12::part_two(&Day12::parse(&input)), Some(29));
+    }
+}
@@ -0,0 +1,142 @@
+use aoc::helpers::parser::{
+    self, literal, map, newline, number, or, pair, preceded, separated, terminated, Input,
+    ParseError, ParseResult, Parser,
+};
+use aoc::Solution;
+use std::cmp::Ordering;
+use std::str::FromStr;
+use Packet::*;
+
+#[derive(Eq, Debug, Clone)]
+pub enum Packet {
+    Literal(u32),
+    List(Vec<Packet>),
+}
+
+/// A number or a list like `[1,[2,[]]]`.
+fn packet(input: Input) -> ParseResult<Packet> {
+    let list = preceded(
+        literal("["),
+        terminated(
+            or(separated(packet, ","), map(literal(""), |_| Vec::new())),
+            literal("]"),
+        ),
+    );
+    or(map(number(), Literal), map(list, List)).parse(input)
+}
+
+impl FromStr for Packet {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parser::parse_all(packet, s)
+    }
+}
+
+impl PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Packet {
+    fn eq(&self, other: &Self) -> bool {
+        matches!(self.cmp(other), Ordering::Equal)
+    }
+}
+
+impl Ord for Packet {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Literal(x), Literal(y)) => x.cmp(y),
+            (Literal(x), List(_)) => List(vec![Literal(*x)]).cmp(other),
+            (List(_), Literal(y)) => self.cmp(&List(vec![Literal(*y)])),
+            (List(x), List(y)) => {
+                for (xx, yy) in x.iter().zip(y.iter()) {
+                    if xx == yy {
+                        continue;
+                    }
+                    return xx.cmp(yy);
+                }
+                x.len().cmp(&y.len())
+            }
+        }
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Parsed = Vec<Packet>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Self::Parsed {
+        aoc::parse_or_panic(Self::try_parse(input))
+    }
+    fn try_parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let pair = pair(terminated(packet, newline()), packet);
+        let pairs = parser::parse_all(parser::blocks(pair), input)?;
+        Ok(pairs.into_iter().flat_map(|(a, b)| [a, b]).collect())
+    }
+    fn part_one(packets: &Self::Parsed) -> Option<u32> {
+        Some(
+            packets
+                .chunks(2)
+                .map(|x| x[0] < x[1])
+                .enumerate()
+                .filter(|(_, x)| *x)
+                .map(|(x, _)| x as u32 + 1)
+                .sum(),
+        )
+    }
+    fn part_two(packets: &Self::Parsed) -> Option<u32> {
+        let div_a = List(vec![List(vec![Literal(2)])]);
+        let div_b = List(vec![List(vec![Literal(6)])]);
+        let mut all = packets.clone();
+        all.append(&mut vec![div_a.clone(), div_b.clone()]);
+        all.sort();
+
+        Some(
+            (all.iter().position(|x| x == &div_a).unwrap() as u32 + 1)
+                * (all.iter().position(|x| x == &div_b).unwrap() as u32 + 1),
+        )
+    }
+}
+fn main() {
+    aoc::solve!(2022, 13, Day13);
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_part_one() {
+        let input = aoc::read_file("test_inputs", Some(2022), 13).unwrap();
+        assert_eq!(Day13::part_one(&Day13::parse(&input)), Some(13));
+    }
+    #[test]
+    fn test_part_two() {
+        let input = aoc::read_file("test_inputs", Some(2022), 13).unwrap();
+        assert_eq!(Day13::part_two(&Day13::parse(&input)), Some(140));
+    }
+    fn p(s: &str) -> Packet {
+        s.parse().unwrap()
+    }
+    #[test]
+    fn test_ordering() {
+        assert_eq!(p("[1,2,3,[1,2]]"), p("[1,2,3,[1,2]]"));
+        assert!(p("[1,2,3,[1,2]]") < p("[1,2,3,[1,2,3]]"));
+        assert!(p("[1,2,3,[1,[[2]]]]") == p("[1,2,3,[1,[2]]]"));
+    }
+    #[test]
+    fn test_invalid_packet() {
+        let error = "[1,[2,x]]".parse::<Packet>().unwrap_err();
+        assert_eq!(error.column, 7);
+        assert_eq!(error.expected, "a number or \"[\"");
+        let error = "[1,[2]".parse::<Packet>().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 7: expected \"]\", found end of input"
+        );
+    }
+}
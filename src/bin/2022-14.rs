@@ -0,0 +1,107 @@
+use aoc::helpers::point::Point2;
+use aoc::Solution;
+
+use hashbrown::HashSet;
+use itertools::Itertools;
+
+const SOURCE: Point2<isize> = Point2::new(500, 0);
+
+/// Where sand tries to go, straight down first and then diagonally to the left and the right.
+const FALLS: [Point2<isize>; 3] = [Point2::new(0, 1), Point2::new(-1, 1), Point2::new(1, 1)];
+
+fn throw_sand(
+    start: Point2<isize>,
+    points: &HashSet<Point2<isize>>,
+    floor: Option<isize>,
+) -> Option<Point2<isize>> {
+    if let Some(limit) = floor {
+        if start.y == limit - 1 {
+            return Some(start);
+        }
+    } else if start.y > points.iter().map(|x| x.y).max().unwrap() {
+        return None;
+    }
+
+    match FALLS
+        .iter()
+        .map(|&d| start + d)
+        .find(|x| !points.contains(x))
+    {
+        Some(next) => throw_sand(next, points, floor),
+        None => Some(start),
+    }
+}
+
+fn create_caves(input: &str) -> HashSet<Point2<isize>> {
+    let mut points = HashSet::new();
+    for line in input.lines() {
+        for (a, b) in line
+            .split(" -> ")
+            .map(|x| {
+                let (a, b) = x.split_once(',').unwrap();
+                Point2::new(a.parse::<isize>().unwrap(), b.parse::<isize>().unwrap())
+            })
+            .tuple_windows()
+        {
+            // the segments are horizontal or vertical, walk them one step at a time
+            let step = (b - a).signum();
+            let mut point = a;
+            points.insert(point);
+            while point != b {
+                point += step;
+                points.insert(point);
+            }
+        }
+    }
+    points
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Parsed = HashSet<Point2<isize>>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Self::Parsed {
+        create_caves(input)
+    }
+    fn part_one(points: &Self::Parsed) -> Option<u32> {
+        let mut points = points.clone();
+        let mut count = 0;
+        while let Some(point) = throw_sand(SOURCE, &points, None) {
+            count += 1;
+            points.insert(point);
+        }
+        Some(count)
+    }
+    fn part_two(points: &Self::Parsed) -> Option<u32> {
+        let mut points = points.clone();
+        let floor = points.iter().map(|x| x.y).max().unwrap() + 2;
+        let mut count = 0;
+        while let Some(point) = throw_sand(SOURCE, &points, Some(floor)) {
+            count += 1;
+            if !points.insert(point) {
+                break;
+            }
+        }
+        Some(count - 1)
+    }
+}
+fn main() {
+    aoc::solve!(2022, 14, Day14);
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_part_one() {
+        let input = aoc::read_file("test_inputs", Some(2022), 14).unwrap();
+        assert_eq!(Day14::part_one(&Day14::parse(&input)), Some(24));
+    }
+    #[test]
+    fn test_part_two() {
+        let input = aoc::read_file("test_inputs", Some(2022), 14).unwrap();
+        assert_eq!(Day14::part_two(&Day14::parse(&input)), Some(93));
+    }
+}
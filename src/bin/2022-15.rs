@@ -0,0 +1,107 @@
+use aoc::helpers::point::Point2;
+use aoc::helpers::ranges::RangeSet;
+use aoc::Solution;
+use hashbrown::HashSet;
+use itertools::Itertools;
+use lazy_static::lazy_static;
+use regex::Regex;
+
+lazy_static! {
+    static ref RE: Regex =
+        Regex::new(r#"Sensor at x=(-?\d+), y=(-?\d+): closest beacon is at x=(-?\d+), y=(-?\d+)"#)
+            .unwrap();
+}
+
+fn parse_pairs(input: &str) -> HashSet<(Point2<isize>, Point2<isize>)> {
+    input
+        .lines()
+        .map(|line| {
+            let (x1, y1, x2, y2) = RE
+                .captures(line)
+                .unwrap()
+                .iter()
+                .skip(1)
+                .map(|x| x.unwrap().as_str().parse::<isize>().unwrap())
+                .next_tuple()
+                .unwrap();
+            (Point2::new(x1, y1), Point2::new(x2, y2))
+        })
+        .collect()
+}
+
+/// Points at distance `r` from `p`, going around from the top, that lie inside the search area.
+fn walk(p: Point2<isize>, r: isize, limit: isize) -> Vec<Point2<isize>> {
+    let mut v = vec![p - Point2::new(0, r)];
+    for d in [(-1, 1), (1, 1), (1, -1), (-1, -1)].map(Point2::from) {
+        for _ in 0..r {
+            let next = *v.last().unwrap() + d;
+            if next.x < 0 || next.x > limit || next.y < 0 || next.y > limit {
+                continue;
+            }
+            v.push(next);
+        }
+    }
+    v.pop();
+    v
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Parsed = HashSet<(Point2<isize>, Point2<isize>)>;
+    type PartOne = usize;
+    type PartTwo = isize;
+
+    fn parse(input: &str) -> Self::Parsed {
+        parse_pairs(input)
+    }
+    fn part_one(pairs: &Self::Parsed) -> Option<usize> {
+        const LINE: isize = 4000000;
+        let mut covered = RangeSet::new();
+        let mut beacons = RangeSet::new();
+        for &(sensor, beacon) in pairs.iter() {
+            if beacon.y == LINE {
+                beacons.insert(beacon.x..beacon.x + 1);
+            }
+            let d = sensor.manhattan(beacon);
+            if (sensor.y - LINE).abs() <= d {
+                let extra = d - (sensor.y - LINE).abs();
+                covered.insert(sensor.x - extra..sensor.x + extra + 1);
+            }
+        }
+        Some(covered.difference(&beacons).len() as usize)
+    }
+
+    fn part_two(pairs: &Self::Parsed) -> Option<isize> {
+        const LIMIT: isize = 4000000;
+        for &(s, b) in pairs.iter() {
+            for p in walk(s, s.manhattan(b) + 1, LIMIT) {
+                if !pairs
+                    .iter()
+                    .filter(|&&x| x != (s, b))
+                    .any(|(s, b)| s.manhattan(p) <= s.manhattan(*b))
+                {
+                    return Some(p.x * 4000000 + p.y);
+                }
+            }
+        }
+        None
+    }
+}
+fn main() {
+    aoc::solve!(2022, 15, Day15);
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_part_one() {
+        let input = aoc::read_file("test_inputs", Some(2022), 15).unwrap();
+        assert_eq!(Day15::part_one(&Day15::parse(&input)), Some(0));
+    }
+    #[test]
+    fn test_part_two() {
+        let input = aoc::read_file("test_inputs", Some(2022), 15).unwrap();
+        assert_eq!(Day15::part_two(&Day15::parse(&input)), Some(56000011));
+    }
+}
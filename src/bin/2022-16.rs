@@ -0,0 +1,148 @@
+use aoc::helpers::bitset::BitSet;
+use aoc::helpers::search::bfs;
+use aoc::Solution;
+use hashbrown::HashMap;
+use itertools::Itertools;
+use lazy_static::lazy_static;
+use regex::Regex;
+
+lazy_static! {
+    static ref RE: Regex =
+        Regex::new(r#"Valve (\w+) has flow rate=(\d+); tunnels? leads? to valves? (.+)"#).unwrap();
+}
+
+// valve n is opened if bit n is set, only valves with flow > 0 get a bit so one word is enough for
+// the inputs, a larger `N` lifts the limit of 64 such valves
+type Valves = BitSet<1>;
+
+#[derive(Debug)]
+pub struct Tunnels {
+    // flow rates of valves worth opening, they are valves 0..flows.len()
+    flows: Vec<u32>,
+    // minutes from any valve to each of the valves worth opening
+    distances: Vec<Vec<u32>>,
+    start: usize,
+}
+
+impl Tunnels {
+    fn valves(&self) -> Valves {
+        (0..self.flows.len()).collect()
+    }
+}
+
+fn parse_tunnels(input: &str) -> Tunnels {
+    let captures = input.lines().map(|x| RE.captures(x).unwrap()).collect_vec();
+
+    // rename vertices from strings to ints, valves with flow > 0 first so they match their bits
+    let (useful, other): (Vec<_>, Vec<_>) = captures.iter().partition(|x| &x[2] != "0");
+    let inner: HashMap<&str, usize> = useful
+        .iter()
+        .chain(other.iter())
+        .enumerate()
+        .map(|(i, x)| (x.get(1).unwrap().as_str(), i))
+        .collect();
+
+    let mut graph = vec![Vec::new(); captures.len()];
+    for cap in captures.iter() {
+        graph[inner[&cap[1]]] = cap[3].split(", ").map(|x| inner[x]).collect_vec();
+    }
+
+    let flows = useful.iter().map(|x| x[2].parse().unwrap()).collect_vec();
+    let distances = (0..graph.len())
+        .map(|from| {
+            (0..flows.len())
+                .map(|to| {
+                    let path = bfs([from], |x| graph[*x].iter().copied(), |x| *x == to);
+                    path.unwrap().cost as u32
+                })
+                .collect()
+        })
+        .collect();
+
+    Tunnels {
+        flows,
+        distances,
+        start: inner["AA"],
+    }
+}
+
+// dfs over the order in which valves get opened, moving straight to the next one, and the most
+// pressure released in `minutes` for every set of valves that ends up open
+fn best_per_valves(tunnels: &Tunnels, minutes: u32) -> HashMap<Valves, u32> {
+    let all = tunnels.valves();
+    let mut best = HashMap::new();
+    let mut stack = vec![(tunnels.start, minutes, Valves::new(), 0)];
+
+    while let Some((at, left, open, released)) = stack.pop() {
+        let entry = best.entry(open).or_insert(0);
+        *entry = (*entry).max(released);
+
+        for next in all.difference(&open).iter() {
+            // walk there and open it
+            let cost = tunnels.distances[at][next] + 1;
+            if cost < left {
+                let left = left - cost;
+                let released = released + tunnels.flows[next] * left;
+                stack.push((next, left, open.with(next), released));
+            }
+        }
+    }
+    best
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Parsed = Tunnels;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Self::Parsed {
+        parse_tunnels(input)
+    }
+    fn part_one(tunnels: &Self::Parsed) -> Option<u32> {
+        best_per_valves(tunnels, 30).into_values().max()
+    }
+
+    // we and the elephant open disjoint sets of valves, so for every set we open the elephant
+    // takes the best of the subsets of what is left
+    fn part_two(tunnels: &Self::Parsed) -> Option<u32> {
+        let best = best_per_valves(tunnels, 26);
+        let all = tunnels.valves();
+
+        let mut ours = best.iter().collect_vec();
+        ours.sort_unstable_by_key(|(_, x)| std::cmp::Reverse(**x));
+
+        let mut total = 0;
+        for (open, released) in ours {
+            // a better pair would have been found from the side releasing more
+            if 2 * released <= total {
+                break;
+            }
+            let elephant = all
+                .difference(open)
+                .subsets()
+                .filter_map(|x| best.get(&x))
+                .max();
+            total = total.max(released + elephant.unwrap_or(&0));
+        }
+        Some(total)
+    }
+}
+fn main() {
+    aoc::solve!(2022, 16, Day16);
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_part_one() {
+        let input = aoc::read_file("test_inputs", Some(2022), 16).unwrap();
+        assert_eq!(Day16::part_one(&Day16::parse(&input)), Some(1651));
+    }
+    #[test]
+    fn test_part_two() {
+        let input = aoc::read_file("test_inputs", Some(2022), 16).unwrap();
+        assert_eq!(Day16::part_two(&Day16::parse(&input)), Some(1707));
+    }
+}
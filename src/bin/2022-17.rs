@@ -0,0 +1,182 @@
+use aoc::helpers::cycle::Detector;
+use aoc::helpers::grid::Grid;
+use aoc::Solution;
+use itertools::Itertools;
+use Shape::*;
+
+enum Shape {
+    Dash,
+    Cross,
+    InvertedL,
+    Vertical,
+    Square,
+}
+
+impl Shape {
+    fn origin(&self, height: usize) -> (usize, usize) {
+        (3, height + 3)
+    }
+
+    fn points(&self) -> Vec<(usize, usize)> {
+        match self {
+            Dash => vec![(0, 0), (1, 0), (2, 0), (3, 0)],
+            Cross => vec![(1, 0), (0, 1), (1, 1), (2, 1), (1, 2)],
+            InvertedL => vec![(0, 0), (1, 0), (2, 0), (2, 1), (2, 2)],
+            Vertical => vec![(0, 0), (0, 1), (0, 2), (0, 3)],
+            Square => vec![(0, 0), (1, 0), (0, 1), (1, 1)],
+        }
+    }
+
+    fn points_relative(&self, position: (usize, usize)) -> Vec<(usize, usize)> {
+        self.points()
+            .iter()
+            .map(|x| (x.0 + position.0, x.1 + position.1))
+            .collect_vec()
+    }
+
+    fn order() -> Vec<Self> {
+        vec![Dash, Cross, InvertedL, Vertical, Square]
+    }
+}
+
+/// Rows of the tower with how far along the shapes and jets are.
+struct Tower<'a> {
+    /// one row per level, between walls in the first and last column and on top of the floor
+    /// in the first row
+    cavern: Grid<bool>,
+    jets: &'a [u8],
+    jet: usize,
+    rocks: usize,
+}
+
+impl<'a> Tower<'a> {
+    fn new(jets: &'a str) -> Self {
+        Tower {
+            cavern: Grid::new(9, 1, true),
+            jets: jets.as_bytes(),
+            jet: 0,
+            rocks: 0,
+        }
+    }
+
+    fn height(&self) -> usize {
+        self.cavern.height() - 1
+    }
+
+    fn fall_rock(&mut self) {
+        let order = Shape::order();
+        let shape = &order[self.rocks % order.len()];
+        self.rocks += 1;
+
+        let height = self.cavern.height();
+        let mut origin = shape.origin(height);
+
+        loop {
+            let new_origin = match self.jets[self.jet] {
+                b'<' => (origin.0 - 1, origin.1),
+                b'>' => (origin.0 + 1, origin.1),
+                _ => unreachable!(),
+            };
+            self.jet = (self.jet + 1) % self.jets.len();
+
+            // jet push
+            if !self.collides(shape, new_origin, height) {
+                origin = new_origin;
+            }
+
+            let new_origin = (origin.0, origin.1 - 1);
+
+            // downwards movement
+            if !self.collides(shape, new_origin, height) {
+                origin = new_origin;
+            } else {
+                // can't move down, time for next shape
+                let increased_height = shape
+                    .points_relative(origin)
+                    .iter()
+                    .map(|x| x.1)
+                    .max()
+                    .unwrap();
+
+                while self.cavern.height() <= increased_height {
+                    self.cavern.push_row((0..9).map(|x| x == 0 || x == 8));
+                }
+
+                for point in shape.points_relative(origin) {
+                    self.cavern[point] = true;
+                }
+                break;
+            }
+        }
+    }
+
+    fn collides(&self, shape: &Shape, origin: (usize, usize), height: usize) -> bool {
+        shape
+            .points_relative(origin)
+            .iter()
+            .map(|(x, y)| if *x == 0 || *x == 8 { (0, 0) } else { (*x, *y) })
+            .filter(|(_, y)| y < &height)
+            .any(|x| self.cavern[x])
+    }
+
+    /// Everything the next rocks depend on: the next shape and jet, and how deep each column
+    /// is open from the top.
+    fn fingerprint(&self) -> (usize, usize, Vec<usize>) {
+        let top = self.cavern.height() - 1;
+        let depths = (1..8)
+            .map(|x| top - self.cavern.column(x).rposition(|&x| x).unwrap())
+            .collect();
+        (self.rocks % Shape::order().len(), self.jet, depths)
+    }
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Parsed = String;
+    type PartOne = usize;
+    type PartTwo = i64;
+
+    fn parse(input: &str) -> Self::Parsed {
+        input.trim().to_string()
+    }
+    fn part_one(jets: &Self::Parsed) -> Option<usize> {
+        let mut tower = Tower::new(jets);
+        for _ in 0..2022 {
+            tower.fall_rock();
+        }
+        Some(tower.height())
+    }
+    fn part_two(jets: &Self::Parsed) -> Option<i64> {
+        const THROWS: usize = 1_000_000_000_000;
+        let mut tower = Tower::new(jets);
+        let mut heights = vec![0];
+        let mut detector = Detector::new();
+
+        let cycle = loop {
+            if let Some(cycle) = detector.push(tower.fingerprint()) {
+                break cycle;
+            }
+            tower.fall_rock();
+            heights.push(tower.height() as i64);
+        };
+        Some(cycle.extrapolate(&heights, THROWS))
+    }
+}
+fn main() {
+    aoc::solve!(2022, 17, Day17);
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_part_one() {
+        let input = aoc::read_file("test_inputs", Some(2022), 17).unwrap();
+        assert_eq!(Day17::part_one(&Day17::parse(&input)), Some(3068));
+    }
+    #[test]
+    fn test_part_two() {
+        let input = aoc::read_file("test_inputs", Some(2022), 17).unwrap();
+        assert_eq!(Day17::part_two(&Day17::parse(&input)), Some(1514285714288));
+    }
+}
@@ -0,0 +1,76 @@
+use aoc::helpers::bounds::Bounds;
+use aoc::helpers::point::Point3;
+use aoc::Solution;
+use elves::parsers::as_vec_vec;
+use hashbrown::HashSet;
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Parsed = HashSet<Point3<isize>>;
+    type PartOne = isize;
+    type PartTwo = isize;
+
+    fn parse(input: &str) -> Self::Parsed {
+        HashSet::from_iter(
+            as_vec_vec::<isize>(input.trim(), '\n', ',')
+                .iter()
+                .map(|x| Point3::new(x[0], x[1], x[2])),
+        )
+    }
+    fn part_one(cubes: &Self::Parsed) -> Option<isize> {
+        let count = cubes
+            .iter()
+            .flat_map(|cube| cube.orthogonal())
+            .filter(|x| !cubes.contains(x))
+            .count() as isize;
+
+        Some(count)
+    }
+    fn part_two(cubes: &Self::Parsed) -> Option<isize> {
+        // one cube of air around the droplet so the fill reaches every side
+        let bounds = Bounds::of(cubes.iter().map(|&x| x.into()))?.grow(1);
+
+        let mut queue = vec![Point3::from(bounds.min)];
+        let mut visited = HashSet::new();
+
+        while let Some(cube) = queue.pop() {
+            for neighbour in cube.orthogonal() {
+                if !bounds.contains(&neighbour.into())
+                    || cubes.contains(&neighbour)
+                    || visited.contains(&neighbour)
+                {
+                    continue;
+                }
+
+                visited.insert(neighbour);
+                queue.push(neighbour);
+            }
+        }
+
+        let count = cubes
+            .iter()
+            .flat_map(|cube| cube.orthogonal())
+            .filter(|x| visited.contains(x))
+            .count() as isize;
+
+        Some(count)
+    }
+}
+fn main() {
+    aoc::solve!(2022, 18, Day18);
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_part_one() {
+        let input = aoc::read_file("test_inputs", Some(2022), 18).unwrap();
+        assert_eq!(Day18::part_one(&Day18::parse(&input)), Some(64));
+    }
+    #[test]
+    fn test_part_two() {
+        let input = aoc::read_file("test_inputs", Some(2022), 18).unwrap();
+        assert_eq!(Day18::part_two(&Day18::parse(&input)), Some(58));
+    }
+}
@@ -1,33 +1,36 @@
+use aoc::helpers::parser::{
+    self, literal, number, pair, preceded, terminated, Input, ParseError, ParseResult, Parser,
+};
+use aoc::Solution;
 use elves::{many_max, many_min};
-use itertools::Itertools;
-use lazy_static::lazy_static;
-use regex::Regex;
-
-lazy_static! {
-    static ref RE: Regex = Regex::new(r#"\d+"#).unwrap();
-}
 
 #[derive(Debug)]
-struct Blueprint {
+pub struct Blueprint {
     ore: u32,
     cly: u32,
     obs: (u32, u32),
     geo: (u32, u32),
 }
 
-impl From<&str> for Blueprint {
-    fn from(value: &str) -> Self {
-        let numbers = RE
-            .captures_iter(value)
-            .map(|x| x[0].parse().unwrap())
-            .collect_vec();
-        Blueprint {
-            ore: numbers[1],
-            cly: numbers[2],
-            obs: (numbers[3], numbers[4]),
-            geo: (numbers[5], numbers[6]),
-        }
-    }
+/// `Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs ...`, the blueprints are
+/// numbered in order so the number is skipped.
+fn blueprint(input: Input) -> ParseResult<Blueprint> {
+    let cost = |robot, ore| preceded(literal(robot), terminated(number::<u32>(), literal(ore)));
+    let (_, input) = pair(literal("Blueprint "), number::<u32>()).parse(input)?;
+    let (ore, input) =
+        preceded(literal(":"), cost(" Each ore robot costs ", " ore.")).parse(input)?;
+    let (cly, input) = cost(" Each clay robot costs ", " ore.").parse(input)?;
+    let (obs, input) = pair(
+        cost(" Each obsidian robot costs ", " ore"),
+        cost(" and ", " clay."),
+    )
+    .parse(input)?;
+    let (geo, input) = pair(
+        cost(" Each geode robot costs ", " ore"),
+        cost(" and ", " obsidian."),
+    )
+    .parse(input)?;
+    Ok((Blueprint { ore, cly, obs, geo }, input))
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -78,14 +81,12 @@ fn simulate(blueprint: &Blueprint, max_time: u32) -> u32 {
         },
     };
 
-    let mut best = u32::min_value();
-    let mut earl = u32::max_value();
+    let mut best = u32::MIN;
+    let mut earl = u32::MAX;
 
     let mut queue = vec![start];
 
-    while !queue.is_empty() {
-        let state = queue.pop().unwrap();
-
+    while let Some(state) = queue.pop() {
         // check if time has run out
         if state.time >= max_time {
             best = many_max!(best, state.ores.geo);
@@ -168,31 +169,41 @@ fn simulate(blueprint: &Blueprint, max_time: u32) -> u32 {
     best
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let blueprints = input.lines().map(Blueprint::from).collect_vec();
-    Some(
-        blueprints
-            .iter()
-            .enumerate()
-            .map(|(idbp, bp)| (idbp + 1) as u32 * simulate(bp, 24))
-            .sum(),
-    )
-}
-pub fn part_two(input: &str) -> Option<u32> {
-    let blueprints = input.lines().map(Blueprint::from).collect_vec();
-    Some(blueprints[..3].iter().map(|bp| simulate(bp, 32)).product())
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Parsed = Vec<Blueprint>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Self::Parsed {
+        aoc::parse_or_panic(Self::try_parse(input))
+    }
+    fn try_parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parser::parse_all(parser::lines(blueprint), input)
+    }
+    fn part_one(blueprints: &Self::Parsed) -> Option<u32> {
+        Some(
+            blueprints
+                .iter()
+                .enumerate()
+                .map(|(idbp, bp)| (idbp + 1) as u32 * simulate(bp, 24))
+                .sum(),
+        )
+    }
+    fn part_two(blueprints: &Self::Parsed) -> Option<u32> {
+        Some(blueprints[..3].iter().map(|bp| simulate(bp, 32)).product())
+    }
 }
 fn main() {
-    let input = &aoc::read_file("inputs", 19);
-    aoc::solve!(1, part_one, input);
-    aoc::solve!(2, part_two, input);
+    aoc::solve!(2022, 19, Day19);
 }
 #[cfg(test)]
 mod tests {
     use super::*;
     #[test]
     fn test_part_one() {
-        let input = aoc::read_file("test_inputs", 19);
-        assert_eq!(part_one(&input), Some(33));
+        let input = aoc::read_file("test_inputs", Some(2022), 19).unwrap();
+        assert_eq!(Day19::part_one(&Day19::parse(&input)), Some(33));
     }
 }
@@ -0,0 +1,95 @@
+use aoc::Solution;
+use elves::parsers::vec_lines;
+use itertools::Itertools;
+
+#[derive(Debug)]
+struct MixedPacket {
+    mixed: isize,
+    number: isize,
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Parsed = Vec<isize>;
+    type PartOne = isize;
+    type PartTwo = isize;
+
+    fn parse(input: &str) -> Self::Parsed {
+        vec_lines(input.trim())
+    }
+    fn part_one(numbers: &Self::Parsed) -> Option<isize> {
+        let mut packets = numbers
+            .iter()
+            .enumerate()
+            .map(|(idx, x)| MixedPacket {
+                mixed: idx as isize,
+                number: *x,
+            })
+            .collect_vec();
+
+        for i in 0..packets.len() {
+            let x = packets.iter().position(|x| x.mixed == i as isize).unwrap();
+            let p = packets.remove(x);
+            packets.insert(
+                (x as isize + p.number).rem_euclid(packets.len() as isize) as usize,
+                p,
+            );
+        }
+
+        let zero = packets.iter().position(|x| x.number == 0).unwrap();
+
+        Some(
+            (1..=3)
+                .map(|x| packets[(x * 1000 + zero) % packets.len()].number)
+                .sum(),
+        )
+    }
+    fn part_two(numbers: &Self::Parsed) -> Option<isize> {
+        const DC_KEY: isize = 811589153;
+        let mut packets = numbers
+            .iter()
+            .enumerate()
+            .map(|(idx, x)| MixedPacket {
+                mixed: idx as isize,
+                number: *x * DC_KEY,
+            })
+            .collect_vec();
+
+        for _ in 0..10 {
+            for i in 0..packets.len() {
+                let x = packets.iter().position(|x| x.mixed == i as isize).unwrap();
+                let p = packets.remove(x);
+                packets.insert(
+                    (x as isize + p.number).rem_euclid(packets.len() as isize) as usize,
+                    p,
+                );
+            }
+        }
+
+        let zero = packets.iter().position(|x| x.number == 0).unwrap();
+
+        Some(
+            (1..=3)
+                .map(|x| packets[(x * 1000 + zero) % packets.len()].number)
+                .sum(),
+        )
+    }
+}
+fn main() {
+    aoc::solve!(2022, 20, Day20);
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_part_one() {
+        let input = aoc::read_file("test_inputs", Some(2022), 20).unwrap();
+        assert_eq!(Day20::part_one(&Day20::parse(&input)), Some(3));
+    }
+    #[test]
+    fn test_part_two() {
+        let input = aoc::read_file("test_inputs", Some(2022), 20).unwrap();
+        assert_eq!(Day20::part_two(&Day20::parse(&input)), Some(1623178306));
+    }
+}
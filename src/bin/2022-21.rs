@@ -1,10 +1,11 @@
+use aoc::Solution;
 use elves::types::{LiteralOther, LiteralOther::*};
 use hashbrown::HashMap;
 use Operation::*;
 
 type Value = LiteralOther<u64, String>;
 
-enum Operation {
+pub enum Operation {
     Say(Value),
     Add(Value, Value),
     Sub(Value, Value),
@@ -31,23 +32,32 @@ impl From<&str> for Operation {
     }
 }
 
-fn monkey_dfs(start: &Value, actions: &HashMap<String, Operation>) -> Option<u64> {
+const HUMAN: &str = "humn";
+
+// with `human_unknown` anything that depends on what the human yells evaluates to None
+fn monkey_dfs(
+    start: &Value,
+    actions: &HashMap<String, Operation>,
+    human_unknown: bool,
+) -> Option<u64> {
+    let dfs = |x| monkey_dfs(x, actions, human_unknown);
     match start {
         Literal(x) => Some(*x),
+        Other(heard) if human_unknown && heard == HUMAN => None,
         Other(heard) => match actions.get(heard)? {
-            Say(x) => monkey_dfs(x, actions),
-            Add(x, y) => Some(monkey_dfs(x, actions)? + monkey_dfs(y, actions)?),
-            Sub(x, y) => Some(monkey_dfs(x, actions)? - monkey_dfs(y, actions)?),
-            Div(x, y) => Some(monkey_dfs(x, actions)? / monkey_dfs(y, actions)?),
-            Mul(x, y) => Some(monkey_dfs(x, actions)? * monkey_dfs(y, actions)?),
+            Say(x) => dfs(x),
+            Add(x, y) => Some(dfs(x)? + dfs(y)?),
+            Sub(x, y) => Some(dfs(x)? - dfs(y)?),
+            Div(x, y) => Some(dfs(x)? / dfs(y)?),
+            Mul(x, y) => Some(dfs(x)? * dfs(y)?),
         },
     }
 }
 
 fn reverse_monkey_dfs(start: &Value, target: u64, actions: &HashMap<String, Operation>) -> u64 {
     let left_right_rec = |x, y, left_f: fn(u64, u64) -> u64, right_f: fn(u64, u64) -> u64| match (
-        monkey_dfs(x, actions),
-        monkey_dfs(y, actions),
+        monkey_dfs(x, actions, true),
+        monkey_dfs(y, actions, true),
     ) {
         (Some(n), None) => reverse_monkey_dfs(y, left_f(n, target), actions),
         (None, Some(n)) => reverse_monkey_dfs(x, right_f(n, target), actions),
@@ -56,6 +66,7 @@ fn reverse_monkey_dfs(start: &Value, target: u64, actions: &HashMap<String, Oper
 
     match start {
         Literal(_) => target,
+        Other(heard) if heard == HUMAN => target,
         Other(heard) => match actions.get(heard) {
             Some(value) => match value {
                 Say(x) => reverse_monkey_dfs(x, target, actions),
@@ -69,48 +80,50 @@ fn reverse_monkey_dfs(start: &Value, target: u64, actions: &HashMap<String, Oper
     }
 }
 
-pub fn part_one(input: &str) -> Option<u64> {
-    let mut actions = HashMap::new();
-    for (monke, action) in input.lines().map(|x| x.split_once(": ").unwrap()) {
-        actions.insert(monke.to_string(), Operation::from(action));
-    }
-    monkey_dfs(&Other("root".to_string()), &actions)
-}
-pub fn part_two(input: &str) -> Option<u64> {
-    let mut actions = HashMap::new();
-    for (monke, action) in input.lines().map(|x| x.split_once(": ").unwrap()) {
-        actions.insert(monke.to_string(), Operation::from(action));
-    }
+pub struct Day21;
 
-    actions.remove("humn");
+impl Solution for Day21 {
+    type Parsed = HashMap<String, Operation>;
+    type PartOne = u64;
+    type PartTwo = u64;
 
-    match &actions["root"] {
-        Add(x, y) | Sub(x, y) | Mul(x, y) | Div(x, y) => {
-            match (monkey_dfs(x, &actions), monkey_dfs(y, &actions)) {
-                (Some(n), None) => Some(reverse_monkey_dfs(y, n, &actions)),
-                (None, Some(n)) => Some(reverse_monkey_dfs(x, n, &actions)),
-                (_, _) => unreachable!(),
+    fn parse(input: &str) -> Self::Parsed {
+        let mut actions = HashMap::new();
+        for (monke, action) in input.lines().map(|x| x.split_once(": ").unwrap()) {
+            actions.insert(monke.to_string(), Operation::from(action));
+        }
+        actions
+    }
+    fn part_one(actions: &Self::Parsed) -> Option<u64> {
+        monkey_dfs(&Other("root".to_string()), actions, false)
+    }
+    fn part_two(actions: &Self::Parsed) -> Option<u64> {
+        match &actions["root"] {
+            Add(x, y) | Sub(x, y) | Mul(x, y) | Div(x, y) => {
+                match (monkey_dfs(x, actions, true), monkey_dfs(y, actions, true)) {
+                    (Some(n), None) => Some(reverse_monkey_dfs(y, n, actions)),
+                    (None, Some(n)) => Some(reverse_monkey_dfs(x, n, actions)),
+                    (_, _) => unreachable!(),
+                }
             }
+            _ => unreachable!(),
         }
-        _ => unreachable!(),
     }
 }
 fn main() {
-    let input = &aoc::read_file("inputs", 21);
-    aoc::solve!(1, part_one, input);
-    aoc::solve!(2, part_two, input);
+    aoc::solve!(2022, 21, Day21);
 }
 #[cfg(test)]
 mod tests {
     use super::*;
     #[test]
     fn test_part_one() {
-        let input = aoc::read_file("test_inputs", 21);
-        assert_eq!(part_one(&input), Some(152));
+        let input = aoc::read_file("test_inputs", Some(2022), 21).unwrap();
+        assert_eq!(Day21::part_one(&Day21::parse(&input)), Some(152));
     }
     #[test]
     fn test_part_two() {
-        let input = aoc::read_file("test_inputs", 21);
-        assert_eq!(part_two(&input), Some(301));
+        let input = aoc::read_file("test_inputs", Some(2022), 21).unwrap();
+        assert_eq!(Day21::part_two(&Day21::parse(&input)), Some(301));
     }
 }
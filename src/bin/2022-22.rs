@@ -0,0 +1,201 @@
+use aoc::helpers::direction::{Direction, Direction::*, Turn};
+use aoc::helpers::grid::Grid;
+use aoc::Solution;
+use std::iter::{from_fn, once};
+
+use Instruction::*;
+
+fn evaluate_direction(direction: Direction) -> usize {
+    match direction {
+        Right => 0,
+        Down => 1,
+        Left => 2,
+        Up => 3,
+    }
+}
+
+pub enum Instruction {
+    Move(usize),
+    Rotate(Turn),
+}
+
+fn parse_direction(directions: &str) -> Vec<Instruction> {
+    let mut directions_vec = Vec::new();
+    let mut chars = directions.chars();
+    let mut buf = None;
+    while let Some(c) = buf.take().or_else(|| chars.next()) {
+        match c {
+            '0'..='9' => {
+                let literal = once(c)
+                    .chain(from_fn(|| {
+                        buf = chars.next();
+                        buf.filter(|cc| cc.is_ascii_digit())
+                    }))
+                    .collect::<String>()
+                    .parse()
+                    .unwrap();
+                directions_vec.push(Move(literal));
+            }
+            x => directions_vec.push(Rotate(match x {
+                'L' => Turn::Left,
+                'R' => Turn::Right,
+                _ => unreachable!(),
+            })),
+        }
+    }
+    directions_vec
+}
+
+/// `Some(true)` for open tiles, `Some(false)` for walls and `None` off the map.
+fn parse_board(board: &str) -> Grid<Option<bool>> {
+    Grid::from_str(board, |c| match c {
+        ' ' => None,
+        '.' => Some(true),
+        '#' => Some(false),
+        _ => unreachable!(),
+    })
+}
+
+fn wrap_part_one(d: &Direction, at: &(usize, usize), board: &Grid<Option<bool>>) -> (usize, usize) {
+    match d {
+        Right => (
+            board.row(at.1).iter().position(Option::is_some).unwrap(),
+            at.1,
+        ),
+        Up => (at.0, board.column(at.0).rposition(Option::is_some).unwrap()),
+        Left => (
+            board.row(at.1).iter().rposition(Option::is_some).unwrap(),
+            at.1,
+        ),
+        Down => (at.0, board.column(at.0).position(Option::is_some).unwrap()),
+    }
+}
+
+fn wrap_part_two(
+    d: &Direction,
+    at: &(usize, usize),
+    board: &Grid<Option<bool>>,
+) -> ((usize, usize), Direction) {
+    // hardcoded mappings for specific cube for part 2 (pretty sure cube shape is the same for
+    // everyone)
+    let (at, d) = match ((at.0 / 50, at.1 / 50), d) {
+        ((1, 0), Left) => ((99, 149 - at.1), Right),
+        ((1, 0), Up) => ((49, at.0 + 100), Right),
+        ((2, 0), Up) => ((at.0 - 100, 100), Up),
+        ((2, 0), Right) => ((0, 149 - at.1), Left),
+        ((2, 0), Down) => ((50, at.0 - 50), Left),
+        ((1, 1), Left) => ((at.1 - 50, 199), Down),
+        ((1, 1), Right) => ((at.1 + 50, 0), Up),
+        ((0, 2), Left) => ((149, 149 - at.1), Right),
+        ((0, 2), Up) => ((99, at.0 + 50), Right),
+        ((1, 2), Right) => ((50, 149 - at.1), Left),
+        ((1, 2), Down) => ((0, at.0 + 100), Left),
+        ((0, 3), Left) => ((at.1 - 100, 149), Down),
+        ((0, 3), Down) => ((at.0 + 100, 49), Down),
+        ((0, 3), Right) => ((at.1 - 100, 0), Up),
+        _ => unreachable!(),
+    };
+
+    (wrap_part_one(&d, &at, board), d)
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Parsed = (Grid<Option<bool>>, Vec<Instruction>);
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Self::Parsed {
+        let (board, directions) = input.split_once("\n\n").unwrap();
+        (parse_board(board), parse_direction(directions.trim()))
+    }
+    fn part_one(notes: &Self::Parsed) -> Option<usize> {
+        let (board, directions) = notes;
+
+        let mut d = Right;
+        let mut at = (board.row(0).iter().position(|x| *x == Some(true))?, 0);
+
+        for direction in directions.iter() {
+            match direction {
+                Rotate(x) => d = d.turn(*x),
+                Move(x) => {
+                    for _ in 0..*x {
+                        let next = board.step(at, d.offset().into());
+                        match next.and_then(|x| board[x]) {
+                            Some(true) => at = next.unwrap(),
+                            Some(false) => break,
+                            None => {
+                                let nat = wrap_part_one(&d, &at, board);
+                                if board[nat].unwrap() {
+                                    at = nat;
+                                } else {
+                                    break;
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        Some(1000 * (at.1 + 1) + 4 * (at.0 + 1) + evaluate_direction(d))
+    }
+    fn part_two(notes: &Self::Parsed) -> Option<usize> {
+        let (board, directions) = notes;
+
+        let mut d = Right;
+        let mut at = (board.row(0).iter().position(|x| *x == Some(true))?, 0);
+
+        for direction in directions.iter() {
+            match direction {
+                Rotate(x) => d = d.turn(*x),
+                Move(x) => {
+                    for _ in 0..*x {
+                        let next = board.step(at, d.offset().into());
+                        match next.and_then(|x| board[x]) {
+                            Some(true) => at = next.unwrap(),
+                            Some(false) => break,
+                            None => {
+                                let (nat, nd) = wrap_part_two(&d, &at, board);
+                                if board[nat].unwrap() {
+                                    at = nat;
+                                    d = nd;
+                                } else {
+                                    break;
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        Some(1000 * (at.1 + 1) + 4 * (at.0 + 1) + evaluate_direction(d))
+    }
+}
+fn main() {
+    aoc::solve!(2022, 22, Day22);
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_part_one() {
+        let input = aoc::read_file("test_inputs", Some(2022), 22).unwrap();
+        assert_eq!(Day22::part_one(&Day22::parse(&input)), Some(6032));
+    }
+    #[test]
+    fn test_rotation() {
+        // right
+        assert_eq!(Right.turn(Turn::Right), Down);
+        assert_eq!(Down.turn(Turn::Right), Left);
+        assert_eq!(Left.turn(Turn::Right), Up);
+        assert_eq!(Up.turn(Turn::Right), Right);
+        // left
+        assert_eq!(Right.turn(Turn::Left), Up);
+        assert_eq!(Up.turn(Turn::Left), Left);
+        assert_eq!(Left.turn(Turn::Left), Down);
+        assert_eq!(Down.turn(Turn::Left), Right);
+    }
+}
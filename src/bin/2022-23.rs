@@ -0,0 +1,131 @@
+use aoc::helpers::bounds::Bounds;
+use aoc::helpers::grid::Grid;
+use aoc::helpers::point::Point2;
+use aoc::Solution;
+
+use hashbrown::{HashMap, HashSet};
+
+const MOVE_ORDER: [[Point2<isize>; 3]; 4] = [
+    // north
+    [Point2::new(0, -1), Point2::new(-1, -1), Point2::new(1, -1)],
+    // south
+    [Point2::new(0, 1), Point2::new(-1, 1), Point2::new(1, 1)],
+    // west
+    [Point2::new(-1, 0), Point2::new(-1, 1), Point2::new(-1, -1)],
+    // east
+    [Point2::new(1, 0), Point2::new(1, 1), Point2::new(1, -1)],
+];
+
+fn parse_input(input: &str) -> HashSet<Point2<isize>> {
+    Grid::from_str(input, |c| c == '#')
+        .iter()
+        .filter(|(_, &elf)| elf)
+        .map(|((x, y), _)| Point2::new(x as isize, y as isize))
+        .collect()
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Parsed = HashSet<Point2<isize>>;
+    type PartOne = isize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Self::Parsed {
+        parse_input(input)
+    }
+    fn part_one(elves: &Self::Parsed) -> Option<isize> {
+        let mut elves = elves.clone();
+
+        for i in 0..10 {
+            let mut proposals = HashMap::new();
+            let mut moves = HashMap::new();
+
+            // first half of turn
+            for e in elves.iter() {
+                if e.neighbours().all(|x| !elves.contains(&x)) {
+                    continue;
+                }
+
+                for direction in MOVE_ORDER.iter().cycle().skip(i).take(4) {
+                    if direction.iter().all(|&x| !elves.contains(&(*e + x))) {
+                        proposals.insert(*e, *e + direction[0]);
+                        *moves.entry(*e + direction[0]).or_insert(0) += 1;
+                        break;
+                    }
+                }
+            }
+
+            // second half of turn
+            let mut new_elves = HashSet::new();
+            for e in elves.iter() {
+                if proposals.contains_key(e) && moves[&proposals[e]] == 1 {
+                    new_elves.insert(proposals[e]);
+                } else {
+                    new_elves.insert(*e);
+                }
+            }
+
+            elves = new_elves;
+        }
+
+        let bounds = Bounds::of(elves.iter().map(|&e| e.into()))?;
+        Some(bounds.volume() - elves.len() as isize)
+    }
+    fn part_two(elves: &Self::Parsed) -> Option<usize> {
+        let mut elves = elves.clone();
+
+        for i in 0..usize::MAX {
+            let mut proposals = HashMap::new();
+            let mut moves = HashMap::new();
+
+            // first half of turn
+            for e in elves.iter() {
+                if e.neighbours().all(|x| !elves.contains(&x)) {
+                    continue;
+                }
+
+                for direction in MOVE_ORDER.iter().cycle().skip(i).take(4) {
+                    if direction.iter().all(|&x| !elves.contains(&(*e + x))) {
+                        proposals.insert(*e, *e + direction[0]);
+                        *moves.entry(*e + direction[0]).or_insert(0) += 1;
+                        break;
+                    }
+                }
+            }
+
+            // second half of turn
+            let mut new_elves = HashSet::new();
+            for e in elves.iter() {
+                if proposals.contains_key(e) && moves[&proposals[e]] == 1 {
+                    new_elves.insert(proposals[e]);
+                } else {
+                    new_elves.insert(*e);
+                }
+            }
+
+            if elves == new_elves {
+                return Some(i + 1);
+            }
+            elves = new_elves;
+        }
+        None
+    }
+}
+fn main() {
+    aoc::solve!(2022, 23, Day23);
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_part_one() {
+        let input = aoc::read_file("test_inputs", Some(2022), 23).unwrap();
+        assert_eq!(Day23::part_one(&Day23::parse(&input)), Some(110));
+    }
+    #[test]
+    fn test_part_two() {
+        let input = aoc::read_file("test_inputs", Some(2022), 23).unwrap();
+        assert_eq!(Day23::part_two(&Day23::parse(&input)), Some(20));
+    }
+}
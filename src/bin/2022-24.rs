@@ -0,0 +1,119 @@
+use aoc::helpers::direction::Direction::{self, *};
+use aoc::helpers::grid::Grid;
+use aoc::helpers::point::Point2;
+use aoc::helpers::search::bfs;
+use aoc::Solution;
+use hashbrown::HashSet;
+
+pub struct Valley {
+    /// snowstorms at the start with the direction they blow in, by their position inside the
+    /// walls
+    snowstorms: HashSet<(Point2<isize>, Direction)>,
+    h: isize,
+    w: isize,
+}
+
+fn parse_snowstorms(valley: &Grid<char>) -> HashSet<(Point2<isize>, Direction)> {
+    let mut snowstorms = HashSet::new();
+    for ((x, y), c) in valley.iter() {
+        let direction = match c {
+            '.' | '#' => continue,
+            '>' => Right,
+            '<' => Left,
+            'v' => Down,
+            '^' => Up,
+            _ => unreachable!(),
+        };
+        // the walls take the first row and column
+        let at = Point2::new(x as isize, y as isize) - Point2::new(1, 1);
+        snowstorms.insert((at, direction));
+    }
+
+    snowstorms
+}
+
+impl Valley {
+    fn start(&self) -> Point2<isize> {
+        Point2::new(0, -1)
+    }
+
+    fn end(&self) -> Point2<isize> {
+        Point2::new(self.w - 1, self.h)
+    }
+
+    /// Snowstorms wrap around, so a position is hit at `minute` by the ones that started
+    /// `minute` steps behind it.
+    fn is_free(&self, at: Point2<isize>, minute: isize) -> bool {
+        if at == self.start() || at == self.end() {
+            return true;
+        }
+        (0..self.w).contains(&at.x)
+            && (0..self.h).contains(&at.y)
+            && Direction::ALL.iter().all(|&direction| {
+                let behind = at - direction.offset() * minute;
+                let origin = Point2::new(behind.x.rem_euclid(self.w), behind.y.rem_euclid(self.h));
+                !self.snowstorms.contains(&(origin, direction))
+            })
+    }
+
+    /// Minutes it takes to get from `from` to `to` when leaving at `minute`.
+    fn cross(&self, from: Point2<isize>, to: Point2<isize>, minute: isize) -> Option<isize> {
+        // the snowstorms are back where they started after this many minutes
+        let period = self.h * self.w;
+        let path = bfs(
+            [(from, minute % period)],
+            |&(at, minute)| {
+                let minute = (minute + 1) % period;
+                // waiting in place is a move too
+                at.orthogonal()
+                    .chain([at])
+                    .filter(move |&x| self.is_free(x, minute))
+                    .map(move |x| (x, minute))
+            },
+            |&(at, _)| at == to,
+        )?;
+        Some(path.cost as isize)
+    }
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Parsed = Valley;
+    type PartOne = isize;
+    type PartTwo = isize;
+
+    fn parse(input: &str) -> Self::Parsed {
+        let valley = Grid::from_str(input.trim(), |c| c);
+        Valley {
+            snowstorms: parse_snowstorms(&valley),
+            h: valley.height() as isize - 2,
+            w: valley.width() as isize - 2,
+        }
+    }
+    fn part_one(valley: &Self::Parsed) -> Option<isize> {
+        valley.cross(valley.start(), valley.end(), 0)
+    }
+    fn part_two(valley: &Self::Parsed) -> Option<isize> {
+        let there = valley.cross(valley.start(), valley.end(), 0)?;
+        let back = there + valley.cross(valley.end(), valley.start(), there)?;
+        Some(back + valley.cross(valley.start(), valley.end(), back)?)
+    }
+}
+fn main() {
+    aoc::solve!(2022, 24, Day24);
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_part_one() {
+        let input = aoc::read_file("test_inputs", Some(2022), 24).unwrap();
+        assert_eq!(Day24::part_one(&Day24::parse(&input)), Some(18));
+    }
+    #[test]
+    fn test_part_two() {
+        let input = aoc::read_file("test_inputs", Some(2022), 24).unwrap();
+        assert_eq!(Day24::part_two(&Day24::parse(&input)), Some(54));
+    }
+}
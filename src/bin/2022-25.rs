@@ -0,0 +1,67 @@
+use aoc::Solution;
+use std::collections::VecDeque;
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Parsed = i64;
+    type PartOne = String;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Self::Parsed {
+        input
+            .lines()
+            .map(|x| {
+                x.chars()
+                    .rev()
+                    .enumerate()
+                    .map(|(idy, y)| match y {
+                        '-' => -(5_i64.pow(idy as u32)),
+                        '=' => -2 * 5_i64.pow(idy as u32),
+                        z => 5_i64.pow(idy as u32) * z.to_digit(10).unwrap() as i64,
+                    })
+                    .sum::<i64>()
+            })
+            .sum()
+    }
+    fn part_one(decimal: &Self::Parsed) -> Option<String> {
+        let mut decimal = *decimal;
+        let mut snafu = VecDeque::new();
+
+        while decimal > 0 {
+            decimal += 2;
+            snafu.push_front(decimal % 5);
+            decimal /= 5;
+        }
+
+        Some(
+            snafu
+                .iter()
+                .map(|x| match x {
+                    0 => '=',
+                    1 => '-',
+                    x => char::from_digit(*x as u32 - 2, 10).unwrap(),
+                })
+                .collect(),
+        )
+    }
+    // day 25 only has one puzzle
+    fn part_two(_: &Self::Parsed) -> Option<u32> {
+        None
+    }
+}
+fn main() {
+    aoc::solve!(2022, 25, Day25);
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_part_one() {
+        let input = aoc::read_file("test_inputs", Some(2022), 25).unwrap();
+        assert_eq!(
+            Day25::part_one(&Day25::parse(&input)),
+            Some("2=-1=0".to_string())
+        );
+    }
+}
@@ -2,103 +2,148 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
+use aoc::download::{self, Config};
+use aoc::{Error, Result};
 use std::{
-    fs::{File, OpenOptions},
-    io::Write,
-    process,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::Path,
 };
 
-const MODULE_TEMPLATE: &str = r###"pub fn part_one(input: &str) -> Option<u32> {
-    None
+/// Fills the placeholders of a file in `templates/`.
+fn render(template: &str, day: u8, year: u32, answer_type: &str) -> String {
+    template
+        .replace("{{DAY_PADDED}}", &format!("{day:02}"))
+        .replace("{{DAY}}", &day.to_string())
+        .replace("{{YEAR}}", &year.to_string())
+        .replace("{{TYPE}}", answer_type)
 }
-pub fn part_two(input: &str) -> Option<u32> {
-    None
-}
-fn main() {
-    let input = &aoc::read_file("inputs", DAY);
-    aoc::solve!(1, part_one, input);
-    aoc::solve!(2, part_two, input);
-}
-#[cfg(test)]
-mod tests {
-    use super::*;
-    #[test]
-    fn test_part_one() {
-        let input = aoc::read_file("test_inputs", DAY);
-        assert_eq!(part_one(&input), None);
-    }
-    #[test]
-    fn test_part_two() {
-        let input = aoc::read_file("test_inputs", DAY);
-        assert_eq!(part_two(&input), None);
-    }
+
+fn read_template(name: &str) -> Result<String> {
+    let folder = aoc::root().join("templates");
+    fs::read_to_string(folder.join(format!("{name}.rs"))).map_err(|e| {
+        let mut available: Vec<String> = fs::read_dir(&folder)
+            .into_iter()
+            .flatten()
+            .filter_map(|x| Some(x.ok()?.path().file_stem()?.to_str()?.to_string()))
+            .collect();
+        available.sort();
+        Error::BadArgument(format!(
+            "Failed to read template `{name}`: {e}. Available: {}",
+            available.join(", ")
+        ))
+    })
 }
-"###;
 
-fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create_new(true).open(path)
+/// Writes the module, refusing to replace a solution that already exists.
+fn create_module(path: &Path, module: &str) -> Result<()> {
+    OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)
+        .and_then(|mut file| file.write_all(module.as_bytes()))
+        .map_err(|e| match e.kind() {
+            io::ErrorKind::AlreadyExists => Error::Exists(path.to_path_buf()),
+            _ => Error::io(path, e),
+        })
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+/// Creates the year's folder if this is its first day.
+fn create_file(path: &Path) -> Result<()> {
+    fs::create_dir_all(path.parent().unwrap())
+        .and_then(|_| {
+            OpenOptions::new()
+                .write(true)
+                .create(true)
+                .truncate(false)
+                .open(path)
+        })
+        .map(|_| ())
+        .map_err(|e| Error::io(path, e))
 }
 
 fn main() {
-    let day = match aoc::parse_args() {
-        Ok(day) => day,
-        Err(_) => {
-            eprintln!("Need to specify a day (as integer). example: `cargo prepare 7`");
-            process::exit(1);
-        }
-    };
+    let mut args = pico_args::Arguments::from_env();
+    let template: String = args
+        .opt_value_from_str("--template")
+        .unwrap_or_else(|e| aoc::exit(e.into()))
+        .unwrap_or_else(|| "plain".to_string());
+    let answer_type: String = args
+        .opt_value_from_str("--type")
+        .unwrap_or_else(|e| aoc::exit(e.into()))
+        .unwrap_or_else(|| "u32".to_string());
+    let year: u32 = args
+        .opt_value_from_str("--year")
+        .unwrap_or_else(|e| aoc::exit(e.into()))
+        .unwrap_or_else(aoc::default_year);
+    let download = args.contains("--download");
+    let block: usize = args
+        .opt_value_from_str("--block")
+        .unwrap_or_else(|e| aoc::exit(e.into()))
+        .unwrap_or(0);
+    let day: u8 = args.free_from_str().unwrap_or_else(|_| {
+        aoc::exit(Error::BadArgument(
+            "Need to specify a day (as integer). example: `cargo prepare 7`".to_string(),
+        ))
+    });
+    aoc::finish_args(args).unwrap_or_else(aoc::exit);
 
+    let template = read_template(&template).unwrap_or_else(aoc::exit);
     let day_padded = format!("{day:02}");
 
-    let input_path = format!("src/inputs/{day_padded}.txt");
-    let example_path = format!("src/test_inputs/{day_padded}.txt");
-    let module_path = format!("src/bin/{day_padded}.rs");
+    let input_path = aoc::input_path("inputs", Some(year), day);
+    let example_path = aoc::input_path("test_inputs", Some(year), day);
+    let module_path = aoc::module_path(year, day);
 
-    let mut file = match safe_create_file(&module_path) {
-        Ok(file) => file,
-        Err(e) => {
-            eprintln!("Failed to create module file: {e}");
-            process::exit(1);
-        }
-    };
+    let module = render(&template, day, year, &answer_type);
+    create_module(&module_path, &module).unwrap_or_else(aoc::exit);
+    println!("Created module file \"{}\"", module_path.display());
 
-    match file.write_all(MODULE_TEMPLATE.replace("DAY", &day.to_string()).as_bytes()) {
-        Ok(_) => {
-            println!("Created module file \"{}\"", &module_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to write module contents: {e}");
-            process::exit(1);
-        }
-    }
+    create_file(&input_path).unwrap_or_else(aoc::exit);
+    println!("Created empty input file \"{}\"", input_path.display());
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to create input file: {e}");
-            process::exit(1);
-        }
-    }
+    create_file(&example_path).unwrap_or_else(aoc::exit);
+    println!("Created empty example file \"{}\"", example_path.display());
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to create example file: {e}");
-            process::exit(1);
-        }
+    if download {
+        let config = Config {
+            input: true,
+            puzzle: true,
+            example: true,
+            block,
+            year: Some(year),
+            ..Config::default()
+        };
+        download::download(day, &config).unwrap_or_else(aoc::exit);
     }
 
     println!("---");
     println!(
-        "🎄 Type `cargo solve {}` to run your solution.",
+        "🎄 Type `cargo solve {} --year {year}` to run your solution.",
         &day_padded
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_templates() {
+        for name in ["grid", "parse", "plain"] {
+            let module = render(&read_template(name).unwrap(), 7, 2022, "u64");
+            assert!(!module.contains("{{"), "placeholder left in {name}");
+            assert!(module.contains("pub struct Day07;"));
+            assert!(module.contains("type PartOne = u64;"));
+            assert!(module.contains("aoc::solve!(2022, 7, Day07);"));
+            assert!(module.contains("aoc::read_file(\"test_inputs\", Some(2022), 7).unwrap()"));
+            // `cargo download --example` fills in these tests
+            let module = download::expect_answer(&module, 1, "24000").unwrap();
+            assert!(download::expect_answer(&module, 2, "45000").is_some());
+        }
+        assert!(read_template("missing")
+            .unwrap_err()
+            .to_string()
+            .ends_with("Available: grid, parse, plain"));
+    }
+}
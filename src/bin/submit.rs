@@ -0,0 +1,279 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use aoc::client::{Client, Outcome};
+use aoc::isolate::Timeouts;
+use aoc::{Error, Status};
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::process;
+use toml::Value;
+
+#[path = "../days.rs"]
+mod days;
+
+/// Every submission is appended here, so known wrong answers are never sent twice.
+const LOG_FILE: &str = "submissions.toml";
+
+#[derive(Debug, Clone, PartialEq)]
+struct Submission {
+    year: u32,
+    day: u8,
+    part: u8,
+    answer: String,
+    outcome: String,
+}
+
+impl Submission {
+    /// An entry of the array of tables, so appending keeps the file valid toml.
+    fn to_toml(&self) -> String {
+        format!(
+            "[[submission]]\nyear = {}\nday = {}\npart = {}\nanswer = {}\noutcome = \"{}\"\n\n",
+            self.year,
+            self.day,
+            self.part,
+            Value::String(self.answer.clone()),
+            self.outcome
+        )
+    }
+}
+
+#[derive(Debug, Default)]
+struct Log(Vec<Submission>);
+
+impl Log {
+    fn parse(s: &str) -> Result<Self, String> {
+        let table = s.parse::<Value>().map_err(|e| e.to_string())?;
+        let Some(entries) = table.get("submission") else {
+            return Ok(Log::default());
+        };
+        let entries = entries
+            .as_array()
+            .ok_or("`submission` should be an array of tables")?;
+
+        let mut submissions = Vec::new();
+        for entry in entries {
+            let field = |name: &str| {
+                entry
+                    .get(name)
+                    .ok_or(format!("submission without `{name}`"))
+            };
+            let number = |name: &str| {
+                field(name)?
+                    .as_integer()
+                    .and_then(|x| u8::try_from(x).ok())
+                    .ok_or(format!("`{name}` should be a small number"))
+            };
+            let string = |name: &str| {
+                field(name)?
+                    .as_str()
+                    .map(str::to_string)
+                    .ok_or(format!("`{name}` should be a string"))
+            };
+            // entries from before the multi-year layout have no year
+            let year = match entry.get("year") {
+                None => aoc::DEFAULT_YEAR,
+                Some(x) => x
+                    .as_integer()
+                    .and_then(|x| u32::try_from(x).ok())
+                    .ok_or("`year` should be a number")?,
+            };
+            submissions.push(Submission {
+                year,
+                day: number("day")?,
+                part: number("part")?,
+                answer: string("answer")?,
+                outcome: string("outcome")?,
+            });
+        }
+        Ok(Log(submissions))
+    }
+
+    fn load() -> aoc::Result<Self> {
+        let path = aoc::root().join(LOG_FILE);
+        match fs::read_to_string(&path) {
+            Ok(s) => Log::parse(&s).map_err(|message| Error::Malformed { path, message }),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Log::default()),
+            Err(e) => Err(Error::io(path, e)),
+        }
+    }
+
+    fn append(submission: &Submission) -> aoc::Result<()> {
+        let path = aoc::root().join(LOG_FILE);
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .and_then(|mut file| file.write_all(submission.to_toml().as_bytes()))
+            .map_err(|e| Error::io(path, e))
+    }
+
+    /// Why `answer` should not be sent, judging by earlier submissions.
+    fn refusal(&self, year: u32, day: u8, part: u8, answer: &str) -> Option<String> {
+        self.0
+            .iter()
+            .filter(|x| x.year == year && x.day == day && x.part == part)
+            .find_map(|x| {
+                // (new, earlier) when both are numbers
+                let numbers = answer.parse::<i64>().ok().zip(x.answer.parse::<i64>().ok());
+                match x.outcome.as_str() {
+                    "correct" | "already_solved" => Some(format!(
+                        "Day {day} part {part} is already solved with `{}`.",
+                        x.answer
+                    )),
+                    "too_high" | "too_low" | "wrong" if x.answer == answer => {
+                        Some(format!("`{answer}` was already rejected ({}).", x.outcome))
+                    }
+                    "too_high" if numbers.is_some_and(|(new, earlier)| new >= earlier) => {
+                        Some(format!(
+                            "`{answer}` is not below `{}`, which was too high.",
+                            x.answer
+                        ))
+                    }
+                    "too_low" if numbers.is_some_and(|(new, earlier)| new <= earlier) => Some(
+                        format!("`{answer}` is not above `{}`, which was too low.", x.answer),
+                    ),
+                    _ => None,
+                }
+            })
+    }
+}
+
+/// How the part ends on the real input, with the answer if it was solved.
+fn compute_answer(year: u32, day: u8, part: u8) -> aoc::Result<Status> {
+    let solution = days::DAYS
+        .iter()
+        .find(|x| x.year == year && x.day == day)
+        .ok_or_else(|| {
+            Error::BadArgument(format!(
+                "There is no solution for day {day} of {year}, create it with `cargo prepare {day} --year {year}`."
+            ))
+        })?;
+    let input = aoc::read_file("inputs", Some(year), day)?;
+    let report = (solution.run)(&input, &Timeouts::default());
+    Ok(report.parts[part as usize - 1].status.clone())
+}
+
+/// Exits with 1, the code for anything that is not a framework error.
+fn refuse(message: String) -> ! {
+    eprintln!("{message}");
+    process::exit(1)
+}
+
+fn main() {
+    let mut args = pico_args::Arguments::from_env();
+    let answer: Option<String> = args
+        .opt_value_from_str("--answer")
+        .unwrap_or_else(|e| aoc::exit(e.into()));
+    let year: u32 = args
+        .opt_value_from_str("--year")
+        .unwrap_or_else(|e| aoc::exit(e.into()))
+        .unwrap_or_else(aoc::default_year);
+    let (day, part): (u8, u8) = match (args.free_from_str(), args.free_from_str()) {
+        (Ok(day), Ok(part)) if part == 1 || part == 2 => (day, part),
+        _ => aoc::exit(Error::BadArgument(
+            "Need to specify a day and a part (1 or 2). example: `cargo submit 7 1`".to_string(),
+        )),
+    };
+    aoc::finish_args(args).unwrap_or_else(aoc::exit);
+
+    let answer = match answer {
+        Some(answer) => answer,
+        None => match compute_answer(year, day, part).unwrap_or_else(aoc::exit) {
+            Status::Solved(answer) => answer,
+            status => refuse(format!("Day {day} part {part} is {}.", status.name())),
+        },
+    };
+    if answer.contains('\n') {
+        refuse(format!(
+            "Can't submit a multi-line answer, read it and pass it with `--answer`:\n{answer}"
+        ));
+    }
+    let log = Log::load().unwrap_or_else(aoc::exit);
+    if let Some(reason) = log.refusal(year, day, part, &answer) {
+        refuse(format!("{reason} Not submitting."));
+    }
+
+    let client = Client::from_env()
+        .map(|x| x.with_year(year))
+        .unwrap_or_else(aoc::exit);
+    println!("Submitting `{answer}` for {year} day {day} part {part}...");
+    let outcome = client.submit(day, part, &answer).unwrap_or_else(aoc::exit);
+
+    let submission = Submission {
+        year,
+        day,
+        part,
+        answer,
+        outcome: outcome.name().to_string(),
+    };
+    if let Err(e) = Log::append(&submission) {
+        eprintln!("Failed to log submission: {e}");
+    }
+
+    match outcome {
+        Outcome::Correct => println!("⭐ That's the right answer!"),
+        Outcome::TooHigh => println!("❌ Too high."),
+        Outcome::TooLow => println!("❌ Too low."),
+        Outcome::Wrong => println!("❌ That's not the right answer."),
+        Outcome::AlreadySolved => println!("Already solved."),
+        Outcome::RateLimited { wait } => {
+            println!("⏳ Submitted too recently, wait {}s.", wait.as_secs())
+        }
+    }
+    if outcome != Outcome::Correct {
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn submission(part: u8, answer: &str, outcome: &str) -> Submission {
+        Submission {
+            year: 2022,
+            day: 1,
+            part,
+            answer: answer.to_string(),
+            outcome: outcome.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_log_roundtrip() {
+        let submissions = vec![
+            submission(1, "24000", "too_high"),
+            submission(2, "say \"hi\"", "wrong"),
+        ];
+        let file: String = submissions.iter().map(Submission::to_toml).collect();
+        assert_eq!(Log::parse(&file).unwrap().0, submissions);
+        assert!(Log::parse("").unwrap().0.is_empty());
+        assert!(Log::parse("[[submission]]\nday = 1").is_err());
+        let old = "[[submission]]\nday = 1\npart = 1\nanswer = \"7\"\noutcome = \"wrong\"";
+        assert_eq!(Log::parse(old).unwrap().0[0].year, 2022);
+    }
+
+    #[test]
+    fn test_refusal() {
+        let log = Log(vec![
+            submission(1, "100", "too_high"),
+            submission(1, "10", "too_low"),
+            submission(1, "ABC", "wrong"),
+            submission(1, "50", "rate_limited"),
+            submission(2, "7", "correct"),
+        ]);
+        assert!(log.refusal(2022, 1, 1, "50").is_none());
+        assert!(log.refusal(2022, 1, 1, "99").is_none());
+        assert!(log.refusal(2022, 1, 1, "ABD").is_none());
+        assert!(log.refusal(2022, 1, 1, "100").is_some());
+        assert!(log.refusal(2022, 1, 1, "150").is_some());
+        assert!(log.refusal(2022, 1, 1, "10").is_some());
+        assert!(log.refusal(2022, 1, 1, "-3").is_some());
+        assert!(log.refusal(2022, 1, 1, "ABC").is_some());
+        assert!(log.refusal(2022, 1, 2, "8").is_some());
+        assert!(log.refusal(2022, 2, 1, "100").is_none());
+        assert!(log.refusal(2021, 1, 2, "8").is_none());
+    }
+}
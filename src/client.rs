@@ -0,0 +1,367 @@
+/*
+ * This file contains template code.
+ * Talks to adventofcode.com with the session cookie from `.env`.
+ */
+use crate::{Error, Result};
+use lazy_static::lazy_static;
+use regex::Regex;
+use reqwest::blocking;
+use reqwest::header;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime};
+use std::{env, fs, thread};
+
+pub const URL: &str = "https://adventofcode.com";
+/// Lets the AoC maintainers know who is sending requests.
+pub const USER_AGENT: &str = "github.com/janezicmatej/aoc2022 by janezic.mj@gmail.com";
+/// Minimum time between two requests of `Client::from_env`, across runs as well.
+pub const THROTTLE: Duration = Duration::from_secs(5);
+
+/// Reply to a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// wrong, without a hint which way
+    Wrong,
+    AlreadySolved,
+    RateLimited {
+        wait: Duration,
+    },
+}
+
+lazy_static! {
+    static ref WAIT: Regex = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+}
+
+impl Outcome {
+    /// Reads the outcome from the `<article>` of the answer page.
+    pub fn parse(html: &str) -> Option<Self> {
+        if html.contains("That's the right answer") {
+            Some(Outcome::Correct)
+        } else if html.contains("your answer is too high") {
+            Some(Outcome::TooHigh)
+        } else if html.contains("your answer is too low") {
+            Some(Outcome::TooLow)
+        } else if html.contains("That's not the right answer") {
+            Some(Outcome::Wrong)
+        } else if html.contains("Did you already complete it?") {
+            Some(Outcome::AlreadySolved)
+        } else if html.contains("You gave an answer too recently") {
+            let wait = WAIT.captures(html).map_or(Duration::ZERO, |x| {
+                let minutes = x.get(1).map_or(0, |m| m.as_str().parse().unwrap());
+                let seconds: u64 = x[2].parse().unwrap();
+                Duration::from_secs(minutes * 60 + seconds)
+            });
+            Some(Outcome::RateLimited { wait })
+        } else {
+            None
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Outcome::Correct => "correct",
+            Outcome::TooHigh => "too_high",
+            Outcome::TooLow => "too_low",
+            Outcome::Wrong => "wrong",
+            Outcome::AlreadySolved => "already_solved",
+            Outcome::RateLimited { .. } => "rate_limited",
+        }
+    }
+}
+
+pub struct Client {
+    http: blocking::Client,
+    url: String,
+    year: u32,
+    throttle: Duration,
+    /// touched on every request, so the throttle also holds between runs
+    stamp: Option<PathBuf>,
+    last: Mutex<Option<SystemTime>>,
+}
+
+impl Client {
+    pub fn new(url: &str, year: u32, token: &str) -> Result<Self> {
+        let mut session = header::HeaderValue::from_str(&format!("session={token}"))
+            .map_err(|e| Error::Env(format!("Error building cookie header: {e}")))?;
+        session.set_sensitive(true);
+        let mut headers = header::HeaderMap::new();
+        headers.insert(header::COOKIE, session);
+
+        let http = blocking::Client::builder()
+            .default_headers(headers)
+            .user_agent(USER_AGENT)
+            .build()
+            .map_err(|e| Error::Http(e.to_string()))?;
+        Ok(Client {
+            http,
+            url: url.trim_end_matches('/').to_string(),
+            year,
+            throttle: Duration::ZERO,
+            stamp: None,
+            last: Mutex::new(None),
+        })
+    }
+
+    pub fn with_throttle(mut self, throttle: Duration, stamp: Option<PathBuf>) -> Self {
+        self.throttle = throttle;
+        self.stamp = stamp;
+        self
+    }
+
+    pub fn with_year(mut self, year: u32) -> Self {
+        self.year = year;
+        self
+    }
+
+    pub fn year(&self) -> u32 {
+        self.year
+    }
+
+    /// Uses `TOKEN` and `YEAR` from `.env`, `AOC_URL` can point it at another server.
+    pub fn from_env() -> Result<Self> {
+        // reads `.env` as well
+        let year = crate::default_year();
+        let token = env::var("TOKEN").map_err(|_| Error::Env("$TOKEN is not set".to_string()))?;
+        let url = env::var("AOC_URL").unwrap_or_else(|_| URL.to_string());
+        let stamp = crate::root().join("target").join(".aoc_last_request");
+        Ok(Client::new(&url, year, &token)?.with_throttle(THROTTLE, Some(stamp)))
+    }
+
+    fn wait(&self) {
+        let mut last = self.last.lock().unwrap();
+        let previous = last.or_else(|| {
+            let stamp = self.stamp.as_ref()?;
+            fs::metadata(stamp).and_then(|x| x.modified()).ok()
+        });
+        if let Some(previous) = previous {
+            let elapsed = previous.elapsed().unwrap_or_default();
+            if elapsed < self.throttle {
+                thread::sleep(self.throttle - elapsed);
+            }
+        }
+
+        *last = Some(SystemTime::now());
+        if let Some(stamp) = &self.stamp {
+            // best effort, the throttle within this run still holds without it
+            fs::write(stamp, "").ok();
+        }
+    }
+
+    /// Sends `request` after the throttle and fails on any non-2xx status.
+    fn send(&self, request: blocking::RequestBuilder) -> Result<String> {
+        self.wait();
+        let response = request
+            .send()
+            .map_err(|e| Error::Http(format!("Request failed: {e}")))?;
+        let status = response.status();
+        let body = response
+            .text()
+            .map_err(|e| Error::Http(format!("Failed to read response: {e}")))?;
+
+        if status.is_success() {
+            Ok(body)
+        } else if body.contains("log in") {
+            Err(Error::Http(format!(
+                "Not logged in ({status}), check that $TOKEN is a valid session cookie."
+            )))
+        } else {
+            Err(Error::Http(format!(
+                "Request failed with {status}: {}",
+                body.trim()
+            )))
+        }
+    }
+
+    pub fn input(&self, day: u8) -> Result<String> {
+        self.send(
+            self.http
+                .get(format!("{}/{}/day/{day}/input", self.url, self.year)),
+        )
+    }
+
+    /// The puzzle page, its articles are in `crate::puzzle`.
+    pub fn puzzle(&self, day: u8) -> Result<String> {
+        self.send(
+            self.http
+                .get(format!("{}/{}/day/{day}", self.url, self.year)),
+        )
+    }
+
+    pub fn submit(&self, day: u8, part: u8, answer: &str) -> Result<Outcome> {
+        let html = self.send(
+            self.http
+                .post(format!("{}/{}/day/{day}/answer", self.url, self.year))
+                .form(&[("level", part.to_string()), ("answer", answer.to_string())]),
+        )?;
+        Outcome::parse(&html).ok_or_else(|| Error::Http(format!("Unexpected response:\n{html}")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread::JoinHandle;
+    use std::time::Instant;
+
+    /// Answers one request with `status` and `body`, returns the url and the received request.
+    fn serve(status: &'static str, body: &'static str) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+            let mut request = String::new();
+            let mut length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(x) = line.to_lowercase().strip_prefix("content-length:") {
+                    length = x.trim().parse().unwrap();
+                }
+                request.push_str(&line);
+                if line == "\r\n" {
+                    break;
+                }
+            }
+            let mut content = vec![0; length];
+            reader.read_exact(&mut content).unwrap();
+            request.push_str(&String::from_utf8(content).unwrap());
+
+            write!(
+                stream,
+                "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+            request
+        });
+        (url, handle)
+    }
+
+    #[test]
+    fn test_parse() {
+        let article = |x: &str| format!("<main><article><p>{x}</p></article></main>");
+        let parse = |x: &str| Outcome::parse(&article(x));
+
+        assert_eq!(
+            parse("That's the right answer!  You are one gold star closer."),
+            Some(Outcome::Correct)
+        );
+        assert_eq!(
+            parse("That's not the right answer; your answer is too high."),
+            Some(Outcome::TooHigh)
+        );
+        assert_eq!(
+            parse("That's not the right answer; your answer is too low."),
+            Some(Outcome::TooLow)
+        );
+        assert_eq!(
+            parse("That's not the right answer.  If you're stuck, make sure you're using the full input data"),
+            Some(Outcome::Wrong)
+        );
+        assert_eq!(
+            parse("You don't seem to be solving the right level.  Did you already complete it?"),
+            Some(Outcome::AlreadySolved)
+        );
+        assert_eq!(
+            parse("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 20s left to wait."),
+            Some(Outcome::RateLimited {
+                wait: Duration::from_secs(80)
+            })
+        );
+        assert_eq!(
+            parse("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 38s left to wait."),
+            Some(Outcome::RateLimited {
+                wait: Duration::from_secs(38)
+            })
+        );
+        assert_eq!(parse("Puzzle inputs differ by user."), None);
+    }
+
+    #[test]
+    fn test_submit() {
+        let (url, request) = serve(
+            "200 OK",
+            "<article><p>That's not the right answer; your answer is too high.</p></article>",
+        );
+        let client = Client::new(&url, 2022, "secret").unwrap();
+        assert_eq!(client.submit(1, 2, "24000").unwrap(), Outcome::TooHigh);
+
+        let request = request.join().unwrap();
+        assert!(request.starts_with("POST /2022/day/1/answer HTTP/1.1"));
+        assert!(request.contains("cookie: session=secret"));
+        assert!(request.ends_with("level=2&answer=24000"));
+    }
+
+    #[test]
+    fn test_input() {
+        let (url, request) = serve("200 OK", "1000\n2000\n");
+        let client = Client::new(&url, 2022, "secret").unwrap();
+        assert_eq!(client.input(7).unwrap(), "1000\n2000\n");
+
+        let request = request.join().unwrap();
+        assert!(request.starts_with("GET /2022/day/7/input HTTP/1.1"));
+        assert!(request.contains(&format!("user-agent: {USER_AGENT}")));
+
+        let (url, request) = serve("200 OK", "<main></main>");
+        let client = Client::new(&url, 2022, "secret").unwrap();
+        assert_eq!(client.puzzle(7).unwrap(), "<main></main>");
+        assert!(request
+            .join()
+            .unwrap()
+            .starts_with("GET /2022/day/7 HTTP/1.1"));
+    }
+
+    #[test]
+    fn test_status() {
+        let (url, _) = serve(
+            "404 Not Found",
+            "Please don't repeatedly request this endpoint before it unlocks! The calendar countdown is synchronized with the server time; the link will be enabled on the calendar the instant this puzzle becomes available.\n",
+        );
+        let client = Client::new(&url, 2022, "secret").unwrap();
+        let error = client.input(25).unwrap_err();
+        assert_eq!(error.exit_code(), 4);
+        assert!(error
+            .to_string()
+            .starts_with("Request failed with 404 Not Found: Please don't repeatedly"));
+
+        let (url, _) = serve(
+            "400 Bad Request",
+            "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n",
+        );
+        let client = Client::new(&url, 2022, "expired").unwrap();
+        assert!(client
+            .input(1)
+            .unwrap_err()
+            .to_string()
+            .starts_with("Not logged in"));
+    }
+
+    #[test]
+    fn test_throttle() {
+        // a stamp shared by two clients, like two runs of `cargo download`
+        let stamp = env::temp_dir().join(format!("aoc_throttle_{}", std::process::id()));
+        let throttle = Duration::from_millis(300);
+        let client = |url: &str| {
+            Client::new(url, 2022, "secret")
+                .unwrap()
+                .with_throttle(throttle, Some(stamp.clone()))
+        };
+
+        let (url, _) = serve("200 OK", "first");
+        assert_eq!(client(&url).input(1).unwrap(), "first");
+
+        let (url, _) = serve("200 OK", "second");
+        let timer = Instant::now();
+        assert_eq!(client(&url).input(1).unwrap(), "second");
+        assert!(timer.elapsed() >= throttle / 2);
+        fs::remove_file(stamp).unwrap();
+    }
+}
@@ -0,0 +1,37 @@
+/*
+ * This file contains template code.
+ * Registers every solution in `./bin/` with the all-days runner, so solutions are called in-process
+ * while they still build as standalone binaries named `<year>-<day>`.
+ */
+use aoc::Day;
+
+macro_rules! days {
+    ($($module:ident = $path:literal => ($year:literal, $day:literal, $solution:ident)),* $(,)?) => {
+        $(
+            // `main` is only used by the standalone binary. Test builds of the runners leave
+            // the days out, their tests already run in each day's own binary.
+            #[cfg(not(test))]
+            #[allow(dead_code)]
+            #[path = $path]
+            mod $module;
+        )*
+
+        #[cfg(not(test))]
+        pub static DAYS: &[Day] = &[
+            $(
+                Day {
+                    year: $year,
+                    day: $day,
+                    run: aoc::run::<$module::$solution>,
+                    bench: aoc::bench::<$module::$solution>,
+                },
+            )*
+        ];
+
+        #[cfg(test)]
+        pub static DAYS: &[Day] = &[];
+    };
+}
+
+// `build.rs` lists the files in `./bin/`
+include!(concat!(env!("OUT_DIR"), "/days.rs"));
@@ -0,0 +1,223 @@
+/*
+ * This file contains template code.
+ * Shared by `cargo download` and `cargo prepare --download`.
+ */
+use crate::client::Client;
+use crate::{puzzle, Error, Result};
+use regex::Regex;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Default)]
+pub struct Config {
+    /// replace non-empty input and example files
+    pub force: bool,
+    pub input: bool,
+    pub puzzle: bool,
+    pub example: bool,
+    /// which code block of the puzzle holds the example
+    pub block: usize,
+    /// `--year`, else `$YEAR`
+    pub year: Option<u32>,
+}
+
+impl Config {
+    /// Without `--puzzle` or `--example` only the input is downloaded.
+    pub fn parse(args: &mut pico_args::Arguments) -> Result<Self> {
+        let puzzle = args.contains("--puzzle");
+        let example = args.contains("--example");
+        Ok(Config {
+            force: args.contains("--force"),
+            input: !puzzle && !example,
+            puzzle,
+            example,
+            block: args.opt_value_from_str("--block")?.unwrap_or(0),
+            year: args.opt_value_from_str("--year")?,
+        })
+    }
+}
+
+pub fn download(day: u8, config: &Config) -> Result<()> {
+    let mut client = Client::from_env()?;
+    if let Some(year) = config.year {
+        client = client.with_year(year);
+    }
+    let year = client.year();
+    if config.input {
+        write_input(&client, year, day, config.force)?;
+    }
+    // both come from the same page, fetch it once
+    if config.puzzle || config.example {
+        let html = client.puzzle(day)?;
+        if config.puzzle {
+            write_puzzle(year, day, &html)?;
+        }
+        if config.example {
+            write_example(year, day, &html, config.block, config.force)?;
+        }
+    }
+    Ok(())
+}
+
+/// Writes `contents` to `path`, creating the year's folder on the way.
+fn write(path: &Path, contents: &str) -> Result<()> {
+    fs::create_dir_all(path.parent().unwrap())
+        .and_then(|_| fs::write(path, contents))
+        .map_err(|e| Error::io(path, e))
+}
+
+fn write_input(client: &Client, year: u32, day: u8, force: bool) -> Result<()> {
+    let input_path = crate::input_path("inputs", Some(year), day);
+    // `cargo prepare` leaves an empty file behind, that one may be replaced
+    if !force && fs::metadata(&input_path).is_ok_and(|x| x.len() > 0) {
+        return Err(Error::Exists(input_path));
+    }
+
+    let input = client.input(day)?;
+    write(&input_path, &input)?;
+    println!("Downloaded input file \"{}\"", input_path.display());
+    Ok(())
+}
+
+/// Always overwrites, part two only shows up once part one is solved.
+fn write_puzzle(year: u32, day: u8, html: &str) -> Result<()> {
+    let markdown = puzzle::markdown(html)
+        .ok_or_else(|| Error::Http(format!("No puzzle description found for day {day}.")))?;
+
+    let puzzle_path = crate::root()
+        .join("src")
+        .join("puzzles")
+        .join(year.to_string())
+        .join(format!("{day:02}.md"));
+    write(&puzzle_path, &markdown)?;
+    println!("Downloaded puzzle \"{}\"", puzzle_path.display());
+    Ok(())
+}
+
+fn parses<T: FromStr>(answer: &str) -> bool {
+    answer.parse::<T>().is_ok()
+}
+
+/// `answer` written as a value of the part's `type PartOne`/`PartTwo` in `module`, `None` when
+/// that type can't hold it.
+fn answer_literal(module: &str, part: usize, answer: &str) -> Option<String> {
+    let name = ["PartOne", "PartTwo"][part - 1];
+    let line = Regex::new(&format!(r"type {name} = ([^;]+);")).unwrap();
+    let answer_type = line.captures(module)?[1].trim().to_string();
+    let integer: fn(&str) -> bool = match answer_type.as_str() {
+        "String" => return Some(format!("{answer:?}.to_string()")),
+        "&str" | "&'static str" => return Some(format!("{answer:?}")),
+        "char" => return answer.parse::<char>().ok().map(|x| format!("{x:?}")),
+        "u8" => parses::<u8>,
+        "u16" => parses::<u16>,
+        "u32" => parses::<u32>,
+        "u64" => parses::<u64>,
+        "u128" => parses::<u128>,
+        "usize" => parses::<usize>,
+        "i8" => parses::<i8>,
+        "i16" => parses::<i16>,
+        "i32" => parses::<i32>,
+        "i64" => parses::<i64>,
+        "i128" => parses::<i128>,
+        "isize" => parses::<isize>,
+        _ => return None,
+    };
+    integer(answer).then(|| answer.to_string())
+}
+
+/// Fills in the expected value of a test that `cargo prepare` generated with `None`. Leaves it
+/// alone when the module's answer type can't hold `answer`.
+pub fn expect_answer(module: &str, part: usize, answer: &str) -> Option<String> {
+    let function = ["part_one", "part_two"][part - 1];
+    let test = Regex::new(&format!(r"(assert_eq!\(\w+::{function}\(.*\)), None\);")).unwrap();
+    let expected = answer_literal(module, part, answer)?;
+    test.is_match(module).then(|| {
+        test.replace(module, format!("${{1}}, Some({expected}));"))
+            .to_string()
+    })
+}
+
+fn write_example(year: u32, day: u8, html: &str, block: usize, force: bool) -> Result<()> {
+    let examples = puzzle::examples(html);
+    let example = examples.get(block).ok_or_else(|| {
+        Error::BadArgument(format!(
+            "No code block {block} in the puzzle for day {day}, it has {}.",
+            examples.len()
+        ))
+    })?;
+
+    let example_path = crate::input_path("test_inputs", Some(year), day);
+    if !force && fs::metadata(&example_path).is_ok_and(|x| x.len() > 0) {
+        return Err(Error::Exists(example_path));
+    }
+    write(&example_path, example)?;
+    println!("Extracted example file \"{}\"", example_path.display());
+
+    let module_path = crate::module_path(year, day);
+    let Ok(mut module) = fs::read_to_string(&module_path) else {
+        return Ok(());
+    };
+    for (part, article) in puzzle::articles(html).into_iter().enumerate() {
+        let part = part + 1;
+        let Some(answer) = puzzle::example_answer(article) else {
+            continue;
+        };
+        match expect_answer(&module, part, &answer) {
+            Some(updated) => {
+                module = updated;
+                println!("Expecting `{answer}` for part {part} of the example");
+            }
+            None if answer_literal(&module, part, &answer).is_none() => eprintln!(
+                "⚠️  Part {part} of the example seems to give `{answer}`, which its answer type \
+                 can't hold. Fill in the test yourself."
+            ),
+            None => println!("Part {part} of the example seems to give `{answer}`"),
+        }
+    }
+    write(&module_path, &module)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expect_answer() {
+        let module = "\
+        type PartOne = u32;
+        type PartTwo = String;
+        assert_eq!(Day05::part_one(&Day05::parse(&input)), None);
+        assert_eq!(Day05::part_two(&Day05::parse(&input)), None);";
+
+        // a number, but the type says it is text
+        let updated = expect_answer(module, 2, "45").unwrap();
+        assert!(updated.ends_with("part_two(&Day05::parse(&input)), Some(\"45\".to_string()));"));
+        let module = expect_answer(module, 2, "MCD").unwrap();
+        assert!(module.ends_with("part_two(&Day05::parse(&input)), Some(\"MCD\".to_string()));"));
+        // text and negative numbers don't fit in a `u32`
+        assert_eq!(expect_answer(&module, 1, "CMZ"), None);
+        assert_eq!(expect_answer(&module, 1, "-3"), None);
+        let module = expect_answer(&module, 1, "24000").unwrap();
+        assert!(module.contains("part_one(&Day05::parse(&input)), Some(24000));"));
+        assert_eq!(expect_answer(&module, 1, "7"), None);
+
+        let module = "type PartOne = char;\nassert_eq!(Day01::part_one(&x), None);";
+        assert!(expect_answer(module, 1, "A")
+            .unwrap()
+            .contains("Some('A'));"));
+    }
+
+    #[test]
+    fn test_config() {
+        let mut args = pico_args::Arguments::from_vec(vec!["--force".into()]);
+        let config = Config::parse(&mut args).unwrap();
+        assert!(config.force && config.input && !config.example);
+
+        let mut args =
+            pico_args::Arguments::from_vec(vec!["--example".into(), "--block".into(), "2".into()]);
+        let config = Config::parse(&mut args).unwrap();
+        assert!(!config.input && config.example && !config.puzzle);
+        assert_eq!(config.block, 2);
+    }
+}
@@ -0,0 +1,123 @@
+/*
+ * This file contains template code.
+ * Errors of the framework code, each with its own exit code for scripts.
+ */
+use std::path::PathBuf;
+use std::{fmt, io, process};
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+#[derive(Debug)]
+pub enum Error {
+    /// a missing or malformed command line argument, like the day
+    BadArgument(String),
+    /// the input or example file of a day can't be read
+    MissingInput { path: PathBuf, source: io::Error },
+    /// a request to adventofcode.com failed or got an unexpected reply
+    Http(String),
+    /// any other file that can't be read or written
+    Io { path: PathBuf, source: io::Error },
+    /// a file like `answers.toml` that can't be parsed
+    Malformed { path: PathBuf, message: String },
+    /// a file that is only replaced with `--force`
+    Exists(PathBuf),
+    /// a missing or invalid setting in `.env`
+    Env(String),
+}
+
+impl Error {
+    pub fn io(path: impl Into<PathBuf>, source: io::Error) -> Self {
+        Error::Io {
+            path: path.into(),
+            source,
+        }
+    }
+
+    /// 1 is left for the solutions themselves, like answers that differ from `answers.toml`
+    /// or a submission that was rejected.
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Error::BadArgument(_) => 2,
+            Error::MissingInput { .. } => 3,
+            Error::Http(_) => 4,
+            Error::Io { .. } => 5,
+            Error::Malformed { .. } => 6,
+            Error::Exists(_) => 7,
+            Error::Env(_) => 8,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::BadArgument(message) | Error::Http(message) | Error::Env(message) => {
+                write!(f, "{message}")
+            }
+            Error::MissingInput { path, source } => {
+                write!(f, "could not read input {}: {source}", path.display())
+            }
+            Error::Io { path, source } => write!(f, "{}: {source}", path.display()),
+            Error::Malformed { path, message } => {
+                write!(f, "could not parse {}: {message}", path.display())
+            }
+            Error::Exists(path) => write!(
+                f,
+                "\"{}\" already exists, use `--force` to replace it.",
+                path.display()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::MissingInput { source, .. } | Error::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl From<pico_args::Error> for Error {
+    fn from(e: pico_args::Error) -> Self {
+        Error::BadArgument(e.to_string())
+    }
+}
+
+/// Prints `error` and exits with its code, for `unwrap_or_else` in the binaries.
+pub fn exit<T>(error: Error) -> T {
+    eprintln!("{error}");
+    process::exit(error.exit_code())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exit_codes() {
+        let errors = [
+            Error::BadArgument("day".to_string()),
+            Error::MissingInput {
+                path: PathBuf::from("07.txt"),
+                source: io::ErrorKind::NotFound.into(),
+            },
+            Error::Http("404".to_string()),
+            Error::io("07.txt", io::ErrorKind::PermissionDenied.into()),
+            Error::Malformed {
+                path: PathBuf::from("answers.toml"),
+                message: "expected a table".to_string(),
+            },
+            Error::Exists(PathBuf::from("07.txt")),
+            Error::Env("$TOKEN is not set".to_string()),
+        ];
+        let mut codes: Vec<i32> = errors.iter().map(Error::exit_code).collect();
+        codes.dedup();
+        assert_eq!(codes, (2..=8).collect::<Vec<_>>());
+        assert_eq!(
+            errors[5].to_string(),
+            "\"07.txt\" already exists, use `--force` to replace it."
+        );
+    }
+}
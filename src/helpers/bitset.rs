@@ -0,0 +1,245 @@
+use std::fmt;
+
+/// A set of small integers, one bit each in `N` words, so it holds values below `64 * N`.
+///
+/// It is `Copy` and hashable, which makes it a cheap memo key for things like the valves that
+/// are open.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct BitSet<const N: usize> {
+    words: [u64; N],
+}
+
+impl<const N: usize> BitSet<N> {
+    pub const CAPACITY: usize = 64 * N;
+
+    pub const fn new() -> Self {
+        BitSet { words: [0; N] }
+    }
+
+    /// Word and bit of `value`.
+    fn locate(value: usize) -> (usize, u64) {
+        assert!(
+            value < Self::CAPACITY,
+            "{value} doesn't fit in a BitSet of {} values",
+            Self::CAPACITY
+        );
+        (value / 64, 1 << (value % 64))
+    }
+
+    /// Returns whether `value` was new to the set.
+    pub fn insert(&mut self, value: usize) -> bool {
+        let (i, bit) = Self::locate(value);
+        let new = self.words[i] & bit == 0;
+        self.words[i] |= bit;
+        new
+    }
+
+    /// Returns whether `value` was in the set.
+    pub fn remove(&mut self, value: usize) -> bool {
+        let (i, bit) = Self::locate(value);
+        let present = self.words[i] & bit != 0;
+        self.words[i] &= !bit;
+        present
+    }
+
+    /// A copy with `value` added, for sets that are passed around by value.
+    pub fn with(mut self, value: usize) -> Self {
+        self.insert(value);
+        self
+    }
+
+    pub fn contains(&self, value: usize) -> bool {
+        value < Self::CAPACITY && self.words[value / 64] & (1 << (value % 64)) != 0
+    }
+
+    /// Number of values in the set.
+    pub fn len(&self) -> usize {
+        self.words.iter().map(|x| x.count_ones() as usize).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|x| *x == 0)
+    }
+
+    /// Combines the sets word by word.
+    fn zip(&self, other: &Self, f: impl Fn(u64, u64) -> u64) -> Self {
+        BitSet {
+            words: std::array::from_fn(|i| f(self.words[i], other.words[i])),
+        }
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        self.zip(other, |a, b| a | b)
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        self.zip(other, |a, b| a & b)
+    }
+
+    /// Values of `self` that are not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        self.zip(other, |a, b| a & !b)
+    }
+
+    pub fn symmetric_difference(&self, other: &Self) -> Self {
+        self.zip(other, |a, b| a ^ b)
+    }
+
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.difference(other).is_empty()
+    }
+
+    pub fn is_superset(&self, other: &Self) -> bool {
+        other.is_subset(self)
+    }
+
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        self.intersection(other).is_empty()
+    }
+
+    /// The values in increasing order.
+    pub fn iter(&self) -> impl Iterator<Item = usize> {
+        let words = self.words;
+        (0..N).flat_map(move |i| {
+            let mut word = words[i];
+            std::iter::from_fn(move || {
+                let bit = word.trailing_zeros() as usize;
+                // clear the lowest bit, it is the one being returned
+                word &= word.wrapping_sub(1);
+                (bit < 64).then_some(64 * i + bit)
+            })
+        })
+    }
+
+    /// Every subset, starting with the empty set. They come in Gray code order, each one differs
+    /// from the one before by a single value.
+    pub fn subsets(&self) -> Subsets<N> {
+        let values: Vec<_> = self.iter().collect();
+        assert!(values.len() < 64, "too many subsets to go through");
+        Subsets {
+            values,
+            current: BitSet::new(),
+            step: 0,
+        }
+    }
+}
+
+impl<const N: usize> Default for BitSet<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> FromIterator<usize> for BitSet<N> {
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let mut set = BitSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<const N: usize> Extend<usize> for BitSet<N> {
+    fn extend<I: IntoIterator<Item = usize>>(&mut self, iter: I) {
+        for value in iter {
+            self.insert(value);
+        }
+    }
+}
+
+/// Like a set of its values, `{1, 5, 64}`.
+impl<const N: usize> fmt::Debug for BitSet<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+/// Iterator returned by [`BitSet::subsets`].
+#[derive(Debug, Clone)]
+pub struct Subsets<const N: usize> {
+    values: Vec<usize>,
+    current: BitSet<N>,
+    /// number of subsets returned so far
+    step: u64,
+}
+
+impl<const N: usize> Iterator for Subsets<N> {
+    type Item = BitSet<N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.step == 1 << self.values.len() {
+            return None;
+        }
+        // step `i` of a Gray code flips the lowest set bit of `i`
+        if self.step > 0 {
+            let value = self.values[self.step.trailing_zeros() as usize];
+            self.current.words[value / 64] ^= 1 << (value % 64);
+        }
+        self.step += 1;
+        Some(self.current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let left = ((1 << self.values.len()) - self.step) as usize;
+        (left, Some(left))
+    }
+}
+
+impl<const N: usize> ExactSizeIterator for Subsets<N> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert() {
+        let mut set = BitSet::<2>::new();
+        assert!(set.insert(3) && set.insert(64) && set.insert(127));
+        assert!(!set.insert(3));
+        assert_eq!(set.len(), 3);
+        assert!(set.contains(64) && !set.contains(63) && !set.contains(1000));
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![3, 64, 127]);
+
+        assert!(set.remove(64) && !set.remove(64));
+        assert_eq!(set, [3, 127].into_iter().collect());
+        assert_eq!(
+            BitSet::<1>::new().with(5).with(0),
+            [0, 5].into_iter().collect()
+        );
+        assert_eq!(format!("{set:?}"), "{3, 127}");
+        assert!(BitSet::<3>::default().is_empty());
+    }
+
+    #[test]
+    fn test_operations() {
+        let a: BitSet<2> = [1, 2, 70].into_iter().collect();
+        let b: BitSet<2> = [2, 3, 70, 100].into_iter().collect();
+        assert_eq!(a.union(&b), [1, 2, 3, 70, 100].into_iter().collect());
+        assert_eq!(a.intersection(&b), [2, 70].into_iter().collect());
+        assert_eq!(a.difference(&b), [1].into_iter().collect());
+        assert_eq!(
+            a.symmetric_difference(&b),
+            [1, 3, 100].into_iter().collect()
+        );
+        assert!(a.intersection(&b).is_subset(&a) && b.is_superset(&a.intersection(&b)));
+        assert!(!a.is_subset(&b) && a.difference(&b).is_disjoint(&b));
+    }
+
+    #[test]
+    fn test_subsets() {
+        let set: BitSet<2> = [0, 5, 64].into_iter().collect();
+        let subsets: Vec<_> = set.subsets().collect();
+        assert_eq!(subsets.len(), 8);
+        assert_eq!(set.subsets().len(), 8);
+        assert_eq!(subsets[0], BitSet::new());
+        assert!(subsets.iter().all(|x| x.is_subset(&set)));
+        // each subset exactly once, one value apart
+        let mut sorted = subsets.clone();
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(sorted.len(), 8);
+        assert!(subsets
+            .windows(2)
+            .all(|x| x[0].symmetric_difference(&x[1]).len() == 1));
+        assert_eq!(BitSet::<1>::new().subsets().count(), 1);
+    }
+}
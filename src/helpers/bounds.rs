@@ -0,0 +1,92 @@
+/// The smallest axis aligned box holding a set of points, both corners included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bounds<const N: usize> {
+    pub min: [isize; N],
+    pub max: [isize; N],
+}
+
+impl<const N: usize> Bounds<N> {
+    /// `None` without any points.
+    pub fn of(points: impl IntoIterator<Item = [isize; N]>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        Some(points.fold(
+            Bounds {
+                min: first,
+                max: first,
+            },
+            |bounds, point| Bounds {
+                min: std::array::from_fn(|axis| bounds.min[axis].min(point[axis])),
+                max: std::array::from_fn(|axis| bounds.max[axis].max(point[axis])),
+            },
+        ))
+    }
+
+    /// Moves every side outwards by `by`, for example to leave room for a flood fill.
+    pub fn grow(mut self, by: isize) -> Self {
+        for axis in 0..N {
+            self.min[axis] -= by;
+            self.max[axis] += by;
+        }
+        self
+    }
+
+    pub fn contains(&self, point: &[isize; N]) -> bool {
+        (0..N).all(|axis| (self.min[axis]..=self.max[axis]).contains(&point[axis]))
+    }
+
+    /// Number of points along each axis.
+    pub fn size(&self) -> [isize; N] {
+        std::array::from_fn(|axis| self.max[axis] - self.min[axis] + 1)
+    }
+
+    /// Number of points inside, the area for two dimensions.
+    pub fn volume(&self) -> isize {
+        self.size().iter().product()
+    }
+
+    /// Every point inside, the last axis changing fastest.
+    pub fn points(&self) -> impl Iterator<Item = [isize; N]> + '_ {
+        let size = self.size();
+        (0..self.volume()).map(move |mut index| {
+            let mut point = self.max;
+            for axis in (0..N).rev() {
+                point[axis] = self.min[axis] + index % size[axis];
+                index /= size[axis];
+            }
+            point
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bounds() {
+        let bounds = Bounds::of([[2, -1], [0, 3], [1, 1]]).unwrap();
+        assert_eq!(
+            bounds,
+            Bounds {
+                min: [0, -1],
+                max: [2, 3]
+            }
+        );
+        assert_eq!(bounds.size(), [3, 5]);
+        assert_eq!(bounds.volume(), 15);
+        assert!(bounds.contains(&[1, 3]) && !bounds.contains(&[3, 0]));
+        assert_eq!(bounds.grow(1).min, [-1, -2]);
+        assert_eq!(Bounds::<3>::of([]), None);
+    }
+
+    #[test]
+    fn test_points() {
+        let bounds = Bounds::of([[0, 5], [1, 6]]).unwrap();
+        assert_eq!(
+            bounds.points().collect::<Vec<_>>(),
+            vec![[0, 5], [0, 6], [1, 5], [1, 6]]
+        );
+        assert_eq!(Bounds::of([[1, 1, 1]]).unwrap().points().count(), 1);
+    }
+}
@@ -0,0 +1,199 @@
+use hashbrown::HashMap;
+use std::hash::Hash;
+
+/// A sequence of states that repeats, the state after `start` steps comes back every `period`
+/// steps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub period: usize,
+}
+
+impl Cycle {
+    /// The step before the end of the first period that has the same state as step `n`.
+    pub fn equivalent(&self, n: usize) -> usize {
+        match n < self.start {
+            true => n,
+            false => self.start + (n - self.start) % self.period,
+        }
+    }
+
+    /// The value after `n` steps for something that changes by the same amount every period,
+    /// like the height of a tower. `values[i]` is the value after `i` steps and has to go up to
+    /// the end of the first period.
+    pub fn extrapolate(&self, values: &[i64], n: usize) -> i64 {
+        let end = self.start + self.period;
+        assert!(
+            values.len() > end,
+            "need {} values, got {}",
+            end + 1,
+            values.len()
+        );
+        if n <= end {
+            return values[n];
+        }
+        let periods = ((n - self.start) / self.period) as i64;
+        let per_period = values[end] - values[self.start];
+        values[self.equivalent(n)] + (periods * per_period)
+    }
+}
+
+/// Floyd's tortoise and hare over `x0`, `f(x0)`, `f(f(x0))`, ... Only ever keeps two states.
+///
+/// Never returns if the sequence doesn't repeat.
+pub fn floyd<T: PartialEq + Clone>(x0: T, mut f: impl FnMut(&T) -> T) -> Cycle {
+    let mut tortoise = f(&x0);
+    let mut hare = f(&tortoise);
+    while tortoise != hare {
+        tortoise = f(&tortoise);
+        let next = f(&hare);
+        hare = f(&next);
+    }
+
+    // the hare is now a multiple of the period ahead, walking both at the same pace from here
+    // makes them meet where the cycle starts
+    let mut start = 0;
+    tortoise = x0;
+    while tortoise != hare {
+        tortoise = f(&tortoise);
+        hare = f(&hare);
+        start += 1;
+    }
+
+    let mut period = 1;
+    hare = f(&tortoise);
+    while tortoise != hare {
+        hare = f(&hare);
+        period += 1;
+    }
+    Cycle { start, period }
+}
+
+/// Brent's algorithm, like [`floyd`] but with fewer calls to `f`.
+///
+/// Never returns if the sequence doesn't repeat.
+pub fn brent<T: PartialEq + Clone>(x0: T, mut f: impl FnMut(&T) -> T) -> Cycle {
+    // the tortoise teleports to the hare at every power of two until the hare runs into it
+    let (mut power, mut period) = (1, 1);
+    let mut tortoise = x0.clone();
+    let mut hare = f(&x0);
+    while tortoise != hare {
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        hare = f(&hare);
+        period += 1;
+    }
+
+    let mut start = 0;
+    tortoise = x0.clone();
+    hare = x0;
+    for _ in 0..period {
+        hare = f(&hare);
+    }
+    while tortoise != hare {
+        tortoise = f(&tortoise);
+        hare = f(&hare);
+        start += 1;
+    }
+    Cycle { start, period }
+}
+
+/// Finds cycles in simulations that are too large to step twice like [`floyd`] and [`brent`]
+/// do. Every step is pushed as a fingerprint, anything that determines the following steps,
+/// and the first fingerprint that comes back closes the cycle.
+#[derive(Debug, Clone)]
+pub struct Detector<K> {
+    seen: HashMap<K, usize>,
+    steps: usize,
+}
+
+impl<K: Eq + Hash> Detector<K> {
+    pub fn new() -> Self {
+        Detector {
+            seen: HashMap::new(),
+            steps: 0,
+        }
+    }
+
+    /// Records the fingerprint of the state after the next step, the first one pushed is the
+    /// state after 0 steps.
+    pub fn push(&mut self, fingerprint: K) -> Option<Cycle> {
+        let step = self.steps;
+        self.steps += 1;
+        match self.seen.get(&fingerprint) {
+            Some(&start) => Some(Cycle {
+                start,
+                period: step - start,
+            }),
+            None => {
+                self.seen.insert(fingerprint, step);
+                None
+            }
+        }
+    }
+}
+
+impl<K: Eq + Hash> Default for Detector<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 0, 1, 2, 3, 4, 5, 2, 3, ...
+    fn step(x: &u32) -> u32 {
+        match x {
+            5 => 2,
+            x => x + 1,
+        }
+    }
+
+    #[test]
+    fn test_algorithms() {
+        let cycle = Cycle {
+            start: 2,
+            period: 4,
+        };
+        assert_eq!(floyd(0, step), cycle);
+        assert_eq!(brent(0, step), cycle);
+        assert_eq!(
+            brent(3, step),
+            Cycle {
+                start: 0,
+                period: 4
+            }
+        );
+
+        let mut detector = Detector::new();
+        let mut x = 0;
+        let found = loop {
+            if let Some(cycle) = detector.push(x) {
+                break cycle;
+            }
+            x = step(&x);
+        };
+        assert_eq!(found, cycle);
+    }
+
+    #[test]
+    fn test_extrapolate() {
+        let cycle = Cycle {
+            start: 2,
+            period: 4,
+        };
+        assert_eq!(cycle.equivalent(1), 1);
+        assert_eq!(cycle.equivalent(11), 3);
+
+        // grows by 1 before the cycle, then 1 + 2 + 0 + 3 every period
+        let values = [0, 1, 2, 3, 5, 5, 8];
+        assert_eq!(cycle.extrapolate(&values, 5), 5);
+        assert_eq!(cycle.extrapolate(&values, 10), 14);
+        assert_eq!(cycle.extrapolate(&values, 11), 15);
+    }
+}
@@ -0,0 +1,79 @@
+use super::point::{Coordinate, Point2};
+use Direction::*;
+
+/// The four ways to move on a grid, with `y` growing downwards like the rows of the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Turn {
+    Left,
+    Right,
+}
+
+impl Direction {
+    /// Clockwise from `Up`.
+    pub const ALL: [Direction; 4] = [Up, Right, Down, Left];
+
+    pub fn turn(self, turn: Turn) -> Self {
+        match turn {
+            Turn::Left => self.turn_left(),
+            Turn::Right => self.turn_right(),
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        match self {
+            Up => Right,
+            Right => Down,
+            Down => Left,
+            Left => Up,
+        }
+    }
+
+    pub fn turn_left(self) -> Self {
+        match self {
+            Up => Left,
+            Left => Down,
+            Down => Right,
+            Right => Up,
+        }
+    }
+
+    pub fn reverse(self) -> Self {
+        self.turn_right().turn_right()
+    }
+
+    /// A single step in this direction.
+    pub fn offset<T: Coordinate>(self) -> Point2<T> {
+        let (o, l) = (T::ZERO, T::ONE);
+        match self {
+            Up => Point2::new(o, -l),
+            Right => Point2::new(l, o),
+            Down => Point2::new(o, l),
+            Left => Point2::new(-l, o),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turn() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn(Turn::Left).turn(Turn::Right), direction);
+            assert_eq!(direction.reverse().offset::<i32>(), -direction.offset());
+        }
+        assert_eq!(Up.turn_right(), Right);
+        assert_eq!(Up.turn_left(), Left);
+        // a right turn is clockwise on screen, where `y` grows downwards
+        assert_eq!(Right.turn_right().offset::<i32>(), Point2::new(0, 1));
+    }
+}
@@ -0,0 +1,364 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// Offsets `(dx, dy)` of the cells sharing an edge, clockwise from the one above like
+/// [`Direction::ALL`](super::direction::Direction::ALL).
+pub const NEIGHBOURS_4: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Offsets `(dx, dy)` of the cells sharing an edge or a corner, clockwise from the one above.
+pub const NEIGHBOURS_8: [(isize, isize); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+/// A rectangle of cells stored row after row, indexed with `(x, y)` where `x` is the column
+/// and `y` the row, counted from the top left corner.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// Maps every character of `input` with `f`, one row per line. Lines shorter than the
+    /// longest one are padded with `f(' ')`, like the ragged map of 2022 day 22.
+    pub fn from_str(input: &str, mut f: impl FnMut(char) -> T) -> Self {
+        let lines: Vec<&str> = input.lines().collect();
+        let width = lines.iter().map(|x| x.chars().count()).max().unwrap_or(0);
+        let mut cells = Vec::with_capacity(width * lines.len());
+        for line in lines.iter() {
+            let mut chars = line.chars();
+            cells.extend((0..width).map(|_| f(chars.next().unwrap_or(' '))));
+        }
+        Grid {
+            cells,
+            width,
+            height: lines.len(),
+        }
+    }
+
+    /// Builds a grid from cells that are already in row order.
+    ///
+    /// # Panics
+    /// When `cells` can't be split into rows of `width`.
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Self {
+        assert!(
+            width > 0 && cells.len().is_multiple_of(width),
+            "{} cells don't make rows of {width}",
+            cells.len()
+        );
+        Grid {
+            height: cells.len() / width,
+            cells,
+            width,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): (usize, usize)) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, (x, y): (usize, usize)) -> Option<&T> {
+        self.contains((x, y))
+            .then(|| &self.cells[y * self.width + x])
+    }
+
+    pub fn get_mut(&mut self, (x, y): (usize, usize)) -> Option<&mut T> {
+        self.contains((x, y))
+            .then(|| &mut self.cells[y * self.width + x])
+    }
+
+    /// Panics like indexing does when `at` is outside of the grid.
+    fn check(&self, at: (usize, usize)) {
+        assert!(
+            self.contains(at),
+            "{at:?} is outside of a {}x{} grid",
+            self.width,
+            self.height
+        );
+    }
+
+    /// Moves from `(x, y)` by `(dx, dy)`, `None` when that leaves the grid.
+    pub fn step(&self, (x, y): (usize, usize), (dx, dy): (isize, isize)) -> Option<(usize, usize)> {
+        let next = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+        self.contains(next).then_some(next)
+    }
+
+    /// Appends a row at the bottom, for grids that grow like the cavern of 2022 day 17.
+    ///
+    /// # Panics
+    /// When `row` isn't as wide as the grid.
+    pub fn push_row(&mut self, row: impl IntoIterator<Item = T>) {
+        self.cells.extend(row);
+        assert_eq!(self.cells.len(), self.width * (self.height + 1));
+        self.height += 1;
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| (i % width, i / width))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// Position of the first cell, row by row, that matches `f`.
+    pub fn position(&self, mut f: impl FnMut(&T) -> bool) -> Option<(usize, usize)> {
+        self.iter().find(|(_, x)| f(x)).map(|(p, _)| p)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics on 0, an empty grid has no rows anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// # Panics
+    /// When `x` is outside of the grid.
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        assert!(
+            x < self.width,
+            "column {x} is outside of a {}x{} grid",
+            self.width,
+            self.height
+        );
+        (0..self.height).map(move |y| &self.cells[y * self.width + x])
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl DoubleEndedIterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Positions from `(x, y)` in steps of `(dx, dy)` up to the edge, without `(x, y)` itself.
+    pub fn ray(
+        &self,
+        (x, y): (usize, usize),
+        (dx, dy): (isize, isize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        std::iter::successors(Some((x, y)), move |&p| self.step(p, (dx, dy))).skip(1)
+    }
+
+    /// Positions on the diagonal through `(x, y)` that goes down to the right, from top to
+    /// bottom.
+    ///
+    /// # Panics
+    /// When `(x, y)` is outside of the grid.
+    pub fn diagonal(&self, (x, y): (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.check((x, y));
+        let back = x.min(y);
+        let start = (x - back, y - back);
+        std::iter::once(start).chain(self.ray(start, (1, 1)))
+    }
+
+    /// Positions on the diagonal through `(x, y)` that goes down to the left, from top to
+    /// bottom.
+    ///
+    /// # Panics
+    /// When `(x, y)` is outside of the grid.
+    pub fn anti_diagonal(
+        &self,
+        (x, y): (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.check((x, y));
+        let back = (self.width - 1 - x).min(y);
+        let start = (x + back, y - back);
+        std::iter::once(start).chain(self.ray(start, (-1, 1)))
+    }
+
+    /// Neighbours sharing an edge with `(x, y)` that are inside the grid.
+    pub fn neighbours_4(&self, at: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        NEIGHBOURS_4.iter().filter_map(move |&d| self.step(at, d))
+    }
+
+    /// Neighbours sharing an edge or a corner with `(x, y)` that are inside the grid.
+    pub fn neighbours_8(&self, at: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        NEIGHBOURS_8.iter().filter_map(move |&d| self.step(at, d))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Grid {
+            cells: vec![fill; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Mirrors the grid over its diagonal, rows become columns.
+    pub fn transpose(&self) -> Self {
+        self.remap(self.height, self.width, |(x, y)| (y, x))
+    }
+
+    /// Turns the grid a quarter clockwise.
+    pub fn rotate_right(&self) -> Self {
+        self.remap(self.height, self.width, |(x, y)| (y, self.height - 1 - x))
+    }
+
+    /// Turns the grid a quarter counterclockwise.
+    pub fn rotate_left(&self) -> Self {
+        self.remap(self.height, self.width, |(x, y)| (self.width - 1 - y, x))
+    }
+
+    /// A `width` by `height` grid where `(x, y)` holds the cell at `from((x, y))` of `self`.
+    fn remap(
+        &self,
+        width: usize,
+        height: usize,
+        from: impl Fn((usize, usize)) -> (usize, usize),
+    ) -> Self {
+        let cells = (0..width * height)
+            .map(|i| self[from((i % width, i / width))].clone())
+            .collect();
+        Grid {
+            cells,
+            width,
+            height,
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        self.check((x, y));
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        self.check((x, y));
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+/// One line per row, for example `grid.map(|x| if *x { '#' } else { '.' })` to look at a
+/// grid of booleans.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "abc\ndef\n";
+
+    #[test]
+    fn test_from_str() {
+        let grid = Grid::from_str(INPUT, |c| c);
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 'f');
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.to_string(), INPUT.trim_end());
+
+        let ragged = Grid::from_str("  #\n#", |c| c == '#');
+        assert_eq!(ragged.row(1), &[true, false, false]);
+        assert_eq!(ragged.position(|x| *x), Some((2, 0)));
+    }
+
+    #[test]
+    fn test_lines() {
+        let grid = Grid::from_str("123\n456\n789", |c| c.to_digit(10).unwrap());
+        assert_eq!(grid.row(1), &[4, 5, 6]);
+        assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), vec![3, 6, 9]);
+        assert_eq!(grid.columns().count(), 3);
+        assert_eq!(
+            grid.ray((1, 2), (0, -1))
+                .map(|x| grid[x])
+                .collect::<Vec<_>>(),
+            vec![5, 2]
+        );
+        assert_eq!(
+            grid.diagonal((2, 1)).map(|x| grid[x]).collect::<Vec<_>>(),
+            vec![2, 6]
+        );
+        assert_eq!(
+            grid.anti_diagonal((1, 1))
+                .map(|x| grid[x])
+                .collect::<Vec<_>>(),
+            vec![3, 5, 7]
+        );
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::new(3, 2, 0);
+        assert_eq!(grid.neighbours_4((0, 0)).count(), 2);
+        assert_eq!(grid.neighbours_4((1, 1)).count(), 3);
+        assert_eq!(grid.neighbours_8((1, 0)).count(), 5);
+        // clockwise from the one above, `y` grows downwards
+        assert_eq!(
+            grid.neighbours_4((1, 0)).collect::<Vec<_>>(),
+            vec![(2, 0), (1, 1), (0, 0)]
+        );
+        assert_eq!(grid.step((2, 1), (1, 0)), None);
+        assert_eq!(grid.step((2, 1), (-2, -1)), Some((0, 0)));
+    }
+
+    #[test]
+    #[should_panic(expected = "(0, 0) is outside of a 0x3 grid")]
+    fn test_anti_diagonal_of_empty_grid() {
+        let _ = Grid::new(0, 3, 0).anti_diagonal((0, 0));
+    }
+
+    #[test]
+    fn test_transform() {
+        let grid = Grid::from_str(INPUT, |c| c);
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_right().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_left().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.rotate_right().rotate_left(), grid);
+        assert_eq!(grid.transpose().transpose(), grid);
+    }
+
+    #[test]
+    fn test_push_row() {
+        let mut grid = Grid::from_vec(2, vec![true, true]);
+        grid.push_row([true, false]);
+        grid[(1, 1)] = true;
+        assert_eq!(grid.height(), 2);
+        assert!(grid.iter().all(|(_, x)| *x));
+    }
+}
@@ -0,0 +1,13 @@
+/*
+ * Code shared by the solutions in `./bin/`, import it with `use aoc::helpers::...`.
+ * Everything here is generic over the year, keep day specific code in its own file.
+ */
+pub mod bitset;
+pub mod bounds;
+pub mod cycle;
+pub mod direction;
+pub mod grid;
+pub mod parser;
+pub mod point;
+pub mod ranges;
+pub mod search;
@@ -0,0 +1,385 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// Where and why the input could not be parsed, `line` and `column` start at 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, found {}",
+            self.line, self.column, self.expected, self.found
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// The part of the input that is left, it keeps the whole input around to tell where an error
+/// happened.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Input<'a> {
+    source: &'a str,
+    offset: usize,
+}
+
+impl<'a> Input<'a> {
+    pub fn new(source: &'a str) -> Self {
+        Input { source, offset: 0 }
+    }
+
+    pub fn rest(&self) -> &'a str {
+        &self.source[self.offset..]
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rest().is_empty()
+    }
+
+    /// Skips `bytes` bytes, they have to end on a char boundary.
+    pub fn advance(self, bytes: usize) -> Self {
+        Input {
+            offset: self.offset + bytes,
+            ..self
+        }
+    }
+
+    /// A failure at the current position.
+    pub fn error(&self, expected: impl Into<String>) -> Failure<'a> {
+        Failure {
+            at: *self,
+            expected: expected.into(),
+        }
+    }
+}
+
+/// Why a parser stopped and where. It is only a position so that trying alternatives stays
+/// cheap, [`parse_all`] turns the last one into a [`ParseError`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Failure<'a> {
+    at: Input<'a>,
+    expected: String,
+}
+
+impl From<Failure<'_>> for ParseError {
+    /// Works out the line and column, and takes the token that starts there as `found`.
+    fn from(failure: Failure<'_>) -> Self {
+        let Input { source, offset } = failure.at;
+        let before = &source[..offset];
+        let line = before.matches('\n').count() + 1;
+        let column = before.rsplit('\n').next().unwrap_or("").chars().count() + 1;
+        let rest = failure.at.rest();
+        let found = match rest.chars().next() {
+            None => "end of input".to_string(),
+            Some('\n') => "end of line".to_string(),
+            Some(c) if c.is_whitespace() => format!("{c:?}"),
+            Some(_) => {
+                let token: String = rest
+                    .chars()
+                    .take_while(|x| !x.is_whitespace())
+                    .take(20)
+                    .collect();
+                format!("{token:?}")
+            }
+        };
+        ParseError {
+            line,
+            column,
+            expected: failure.expected,
+            found,
+        }
+    }
+}
+
+/// A parsed value and the input after it.
+pub type ParseResult<'a, T> = Result<(T, Input<'a>), Failure<'a>>;
+
+/// Anything that takes a value from the front of the input. Plain functions and closures from
+/// [`Input`] to [`ParseResult`] are parsers, which is how recursive parsers are written.
+pub trait Parser<'a, T> {
+    fn parse(&self, input: Input<'a>) -> ParseResult<'a, T>;
+}
+
+impl<'a, T, F> Parser<'a, T> for F
+where
+    F: Fn(Input<'a>) -> ParseResult<'a, T>,
+{
+    fn parse(&self, input: Input<'a>) -> ParseResult<'a, T> {
+        self(input)
+    }
+}
+
+/// Runs `parser` on all of `source`, only trailing whitespace may be left over.
+pub fn parse_all<'a, T>(parser: impl Parser<'a, T>, source: &'a str) -> Result<T, ParseError> {
+    let (value, rest) = parser.parse(Input::new(source))?;
+    let trimmed = rest.rest().trim_start();
+    let rest = rest.advance(rest.rest().len() - trimmed.len());
+    match rest.is_empty() {
+        true => Ok(value),
+        false => Err(rest.error("end of input").into()),
+    }
+}
+
+/// Exactly `text`.
+pub fn literal<'a>(text: &'static str) -> impl Parser<'a, ()> {
+    move |input: Input<'a>| match input.rest().starts_with(text) {
+        true => Ok(((), input.advance(text.len()))),
+        false => Err(input.error(format!("{text:?}"))),
+    }
+}
+
+/// The value paired with the first of `options` that the input starts with.
+pub fn one_of<'a, T: Clone>(options: &'static [(&'static str, T)]) -> impl Parser<'a, T> {
+    move |input: Input<'a>| {
+        options
+            .iter()
+            .find(|(text, _)| input.rest().starts_with(text))
+            .map(|(text, value)| (value.clone(), input.advance(text.len())))
+            .ok_or_else(|| {
+                let texts: Vec<_> = options.iter().map(|(x, _)| format!("{x:?}")).collect();
+                input.error(format!("one of {}", texts.join(", ")))
+            })
+    }
+}
+
+/// Skips spaces and tabs, but not newlines.
+pub fn spaces<'a>() -> impl Parser<'a, ()> {
+    move |input: Input<'a>| {
+        let rest = input.rest();
+        let skipped = rest.len() - rest.trim_start_matches([' ', '\t']).len();
+        Ok(((), input.advance(skipped)))
+    }
+}
+
+/// A line break between two lines.
+pub fn newline<'a>() -> impl Parser<'a, ()> {
+    move |input: Input<'a>| match input.rest().starts_with('\n') {
+        true => Ok(((), input.advance(1))),
+        false => Err(input.error("end of line")),
+    }
+}
+
+/// Letters and digits, like the name of a valve.
+pub fn word<'a>() -> impl Parser<'a, &'a str> {
+    move |input: Input<'a>| {
+        let rest = input.rest();
+        let len = rest.len() - rest.trim_start_matches(char::is_alphanumeric).len();
+        match len {
+            0 => Err(input.error("a word")),
+            _ => Ok((&rest[..len], input.advance(len))),
+        }
+    }
+}
+
+/// Digits after the first `start` bytes, parsed as `T` together with them. Numbers that don't
+/// fit are errors too.
+fn digits<'a, T: FromStr>(input: Input<'a>, start: usize, expected: &str) -> ParseResult<'a, T> {
+    let rest = input.rest();
+    let len = rest[start..].len()
+        - rest[start..]
+            .trim_start_matches(|x: char| x.is_ascii_digit())
+            .len();
+    if len == 0 {
+        return Err(input.error(expected));
+    }
+    match rest[..start + len].parse() {
+        Ok(value) => Ok((value, input.advance(start + len))),
+        Err(_) => Err(input.error(format!("{expected} that fits"))),
+    }
+}
+
+/// A number without a sign.
+pub fn number<'a, T: FromStr>() -> impl Parser<'a, T> {
+    move |input: Input<'a>| digits(input, 0, "a number")
+}
+
+/// A number with an optional `-` or `+`.
+pub fn signed<'a, T: FromStr>() -> impl Parser<'a, T> {
+    move |input: Input<'a>| {
+        let sign = input.rest().starts_with(['-', '+']) as usize;
+        digits(input, sign, "a signed number")
+    }
+}
+
+pub fn map<'a, A, B>(parser: impl Parser<'a, A>, f: impl Fn(A) -> B) -> impl Parser<'a, B> {
+    move |input: Input<'a>| {
+        let (value, input) = parser.parse(input)?;
+        Ok((f(value), input))
+    }
+}
+
+/// `first` if it matches, `second` if `first` failed right away. When `first` got further than
+/// that before failing its error is closer to what is wrong and `second` isn't tried. The error
+/// lists what both expected when neither got anywhere.
+pub fn or<'a, T>(first: impl Parser<'a, T>, second: impl Parser<'a, T>) -> impl Parser<'a, T> {
+    move |input: Input<'a>| match first.parse(input) {
+        Ok(x) => Ok(x),
+        Err(a) if a.at.offset != input.offset => Err(a),
+        Err(a) => second
+            .parse(input)
+            .map_err(|b| match b.at.offset == input.offset {
+                true => Failure {
+                    expected: format!("{} or {}", a.expected, b.expected),
+                    ..b
+                },
+                false => b,
+            }),
+    }
+}
+
+pub fn pair<'a, A, B>(
+    first: impl Parser<'a, A>,
+    second: impl Parser<'a, B>,
+) -> impl Parser<'a, (A, B)> {
+    move |input: Input<'a>| {
+        let (a, input) = first.parse(input)?;
+        let (b, input) = second.parse(input)?;
+        Ok(((a, b), input))
+    }
+}
+
+/// `parser` after `prefix`, keeping only what `parser` returns.
+pub fn preceded<'a, P, T>(
+    prefix: impl Parser<'a, P>,
+    parser: impl Parser<'a, T>,
+) -> impl Parser<'a, T> {
+    map(pair(prefix, parser), |(_, x)| x)
+}
+
+/// `parser` before `suffix`, keeping only what `parser` returns.
+pub fn terminated<'a, T, S>(
+    parser: impl Parser<'a, T>,
+    suffix: impl Parser<'a, S>,
+) -> impl Parser<'a, T> {
+    map(pair(parser, suffix), |(x, _)| x)
+}
+
+/// One or more `parser` with `separator` between them, like `79, 98`. Whatever follows a
+/// separator has to be another `parser`, unless only whitespace is left of the input.
+pub fn separated<'a, T>(
+    parser: impl Parser<'a, T>,
+    separator: &'static str,
+) -> impl Parser<'a, Vec<T>> {
+    move |input: Input<'a>| {
+        let (first, mut input) = parser.parse(input)?;
+        let mut values = vec![first];
+        while let Some(rest) = input.rest().strip_prefix(separator) {
+            // a trailing separator, like the newlines at the end of the input, ends the list
+            let next = input.advance(input.rest().len() - rest.len());
+            if rest.trim_start().is_empty() {
+                break;
+            }
+            let (value, next) = parser.parse(next)?;
+            values.push(value);
+            input = next;
+        }
+        Ok((values, input))
+    }
+}
+
+/// One `parser` per line.
+pub fn lines<'a, T>(parser: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    separated(parser, "\n")
+}
+
+/// Blocks of lines split by blank lines, `parser` gets one block at a time.
+pub fn blocks<'a, T>(parser: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    separated(parser, "\n\n")
+}
+
+/// A `key: value` line, possibly indented, like `  Starting items: 79, 98`.
+pub fn key_value<'a, T>(key: &'static str, value: impl Parser<'a, T>) -> impl Parser<'a, T> {
+    preceded(pair(spaces(), pair(literal(key), literal(": "))), value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_numbers() {
+        assert_eq!(parse_all(number::<u32>(), "42\n"), Ok(42));
+        assert_eq!(parse_all(signed::<i64>(), "-17"), Ok(-17));
+        assert_eq!(parse_all(signed::<i64>(), "+3"), Ok(3));
+        assert_eq!(
+            parse_all(separated(signed::<i32>(), ", "), "1, -2, 3"),
+            Ok(vec![1, -2, 3])
+        );
+
+        let error = parse_all(number::<u8>(), "300").unwrap_err();
+        assert_eq!(error.expected, "a number that fits");
+        let error = parse_all(number::<u32>(), "-1").unwrap_err();
+        assert_eq!(
+            (error.expected.as_str(), error.found.as_str()),
+            ("a number", "\"-1\"")
+        );
+    }
+
+    #[test]
+    fn test_blocks() {
+        let item = preceded(literal("Monkey "), number::<u32>());
+        let block = pair(
+            terminated(item, newline()),
+            key_value("Items", separated(number::<u32>(), ", ")),
+        );
+        let input = "Monkey 0\n  Items: 1, 2\n\nMonkey 1\n  Items: 3\n";
+        assert_eq!(
+            parse_all(blocks(block), input),
+            Ok(vec![(0, vec![1, 2]), (1, vec![3])])
+        );
+        assert_eq!(parse_all(lines(word()), "ab\ncd"), Ok(vec!["ab", "cd"]));
+        // pasted inputs often end with a blank line or stray spaces
+        assert_eq!(parse_all(lines(word()), "ab\ncd\n\n"), Ok(vec!["ab", "cd"]));
+        assert_eq!(parse_all(lines(word()), "ab\n  \n"), Ok(vec!["ab"]));
+        let two_lines = pair(terminated(word(), newline()), word());
+        assert_eq!(
+            parse_all(blocks(two_lines), "ab\ncd\n\nef\ngh\n\n"),
+            Ok(vec![("ab", "cd"), ("ef", "gh")])
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let input = "R 4\nU x\n";
+        let step = pair(
+            terminated(one_of(&[("R", 'R'), ("U", 'U')]), literal(" ")),
+            number::<u32>(),
+        );
+        let error = parse_all(lines(step), input).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 3: expected a number, found \"x\""
+        );
+
+        let error = parse_all(lines(number::<u32>()), "1\n2 3").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.expected, "end of input");
+
+        let truncated = parse_all(pair(number::<u32>(), literal(",")), "7").unwrap_err();
+        assert_eq!(truncated.found, "end of input");
+
+        let either = or(map(number::<u32>(), Some), map(literal("-"), |_| None));
+        assert_eq!(
+            parse_all(lines(|x| either.parse(x)), "1\n-"),
+            Ok(vec![Some(1), None])
+        );
+        let error = parse_all(either, "?").unwrap_err();
+        assert_eq!(error.expected, "a number or \"-\"");
+
+        // the first alternative got to the second line, so its error is the one to report
+        let block = or(
+            pair(terminated(number::<u32>(), newline()), number::<u32>()),
+            pair(number(), number()),
+        );
+        let error = parse_all(block, "1\nx").unwrap_err();
+        assert_eq!((error.line, error.expected.as_str()), (2, "a number"));
+    }
+}
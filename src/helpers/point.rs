@@ -0,0 +1,272 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// Signed integers that can be used as coordinates of a point.
+pub trait Coordinate:
+    Copy + Ord + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Neg<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn abs(self) -> Self;
+    fn signum(self) -> Self;
+}
+
+macro_rules! coordinate {
+    ($($t:ty),*) => {
+        $(
+            impl Coordinate for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn abs(self) -> Self {
+                    <$t>::abs(self)
+                }
+                fn signum(self) -> Self {
+                    <$t>::signum(self)
+                }
+            }
+        )*
+    };
+}
+
+coordinate!(i8, i16, i32, i64, i128, isize);
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+/// Everything that only needs the coordinates one at a time, written once for both types.
+macro_rules! point {
+    ($point:ident, $($axis:ident),+) => {
+        impl<T: Coordinate> $point<T> {
+            pub const fn new($($axis: T),+) -> Self {
+                $point { $($axis),+ }
+            }
+
+            /// Sum of the distances along each axis.
+            pub fn manhattan(self, other: Self) -> T {
+                let d = self - other;
+                T::ZERO $(+ d.$axis.abs())+
+            }
+
+            /// Largest distance along any axis, the number of king moves between the points.
+            pub fn chebyshev(self, other: Self) -> T {
+                let d = self - other;
+                [$(d.$axis.abs()),+].into_iter().max().unwrap()
+            }
+
+            /// -1, 0 or 1 on every axis, a single step towards where `self` points.
+            pub fn signum(self) -> Self {
+                $point { $($axis: self.$axis.signum()),+ }
+            }
+        }
+
+        impl<T: Coordinate> Add for $point<T> {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                $point { $($axis: self.$axis + other.$axis),+ }
+            }
+        }
+
+        impl<T: Coordinate> Sub for $point<T> {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                $point { $($axis: self.$axis - other.$axis),+ }
+            }
+        }
+
+        impl<T: Coordinate> Mul<T> for $point<T> {
+            type Output = Self;
+
+            fn mul(self, scalar: T) -> Self {
+                $point { $($axis: self.$axis * scalar),+ }
+            }
+        }
+
+        impl<T: Coordinate> Neg for $point<T> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                $point { $($axis: -self.$axis),+ }
+            }
+        }
+
+        impl<T: Coordinate> AddAssign for $point<T> {
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+
+        impl<T: Coordinate> SubAssign for $point<T> {
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+    };
+}
+
+point!(Point2, x, y);
+point!(Point3, x, y, z);
+
+impl<T: Coordinate> Point2<T> {
+    /// The points sharing an edge, clockwise from `(0, -1)`.
+    pub fn orthogonal(self) -> impl Iterator<Item = Self> {
+        let (o, l) = (T::ZERO, T::ONE);
+        [(o, -l), (l, o), (o, l), (-l, o)]
+            .into_iter()
+            .map(move |(x, y)| self + Point2::new(x, y))
+    }
+
+    /// The points sharing only a corner, clockwise from `(1, -1)`.
+    pub fn diagonal(self) -> impl Iterator<Item = Self> {
+        let l = T::ONE;
+        [(l, -l), (l, l), (-l, l), (-l, -l)]
+            .into_iter()
+            .map(move |(x, y)| self + Point2::new(x, y))
+    }
+
+    /// The points sharing an edge or a corner.
+    pub fn neighbours(self) -> impl Iterator<Item = Self> {
+        self.orthogonal().chain(self.diagonal())
+    }
+}
+
+impl<T: Coordinate> Point3<T> {
+    /// The points sharing a face.
+    pub fn orthogonal(self) -> impl Iterator<Item = Self> {
+        let (o, l) = (T::ZERO, T::ONE);
+        [
+            (l, o, o),
+            (-l, o, o),
+            (o, l, o),
+            (o, -l, o),
+            (o, o, l),
+            (o, o, -l),
+        ]
+        .into_iter()
+        .map(move |(x, y, z)| self + Point3::new(x, y, z))
+    }
+
+    /// The points sharing a face, an edge or a corner.
+    pub fn neighbours(self) -> impl Iterator<Item = Self> {
+        let steps = [-T::ONE, T::ZERO, T::ONE];
+        let zero = Point3::new(T::ZERO, T::ZERO, T::ZERO);
+        steps
+            .into_iter()
+            .flat_map(move |x| steps.into_iter().map(move |y| (x, y)))
+            .flat_map(move |(x, y)| steps.into_iter().map(move |z| Point3::new(x, y, z)))
+            .filter(move |&d| d != zero)
+            .map(move |d| self + d)
+    }
+}
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Point2 { x, y }
+    }
+}
+
+impl<T> From<Point2<T>> for (T, T) {
+    fn from(p: Point2<T>) -> Self {
+        (p.x, p.y)
+    }
+}
+
+impl<T> From<[T; 2]> for Point2<T> {
+    fn from([x, y]: [T; 2]) -> Self {
+        Point2 { x, y }
+    }
+}
+
+impl<T> From<Point2<T>> for [T; 2] {
+    fn from(p: Point2<T>) -> Self {
+        [p.x, p.y]
+    }
+}
+
+impl<T> From<[T; 3]> for Point3<T> {
+    fn from([x, y, z]: [T; 3]) -> Self {
+        Point3 { x, y, z }
+    }
+}
+
+impl<T> From<Point3<T>> for [T; 3] {
+    fn from(p: Point3<T>) -> Self {
+        [p.x, p.y, p.z]
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Point2<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Point3<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hashbrown::HashSet;
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Point2::new(3, -2);
+        let b = Point2::new(-1, 4);
+        assert_eq!(a + b, Point2::new(2, 2));
+        assert_eq!(a - b, Point2::new(4, -6));
+        assert_eq!(b * 3, Point2::new(-3, 12));
+        assert_eq!(-a, Point2::new(-3, 2));
+        assert_eq!((a - b).signum(), Point2::new(1, -1));
+
+        let mut c = Point3::new(1_i64, 2, 3);
+        c += Point3::new(1, 1, 1);
+        c -= Point3::new(0, 0, 4);
+        assert_eq!(c, Point3::from([2, 3, 0]));
+        assert_eq!(c.to_string(), "(2, 3, 0)");
+    }
+
+    #[test]
+    fn test_distance() {
+        let a = Point2::new(8_isize, 7);
+        let b = Point2::new(2, 10);
+        assert_eq!(a.manhattan(b), 9);
+        assert_eq!(a.chebyshev(b), 6);
+        assert_eq!(Point3::new(1, -1, 0).manhattan(Point3::new(-1, 1, 0)), 4);
+        assert_eq!(Point3::new(1, -1, 5).chebyshev(Point3::new(-1, 1, 0)), 5);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let p = Point2::new(0, 0);
+        assert_eq!(
+            p.orthogonal().collect::<Vec<_>>(),
+            [(0, -1), (1, 0), (0, 1), (-1, 0)].map(Point2::from)
+        );
+        let around: HashSet<_> = p.neighbours().collect();
+        assert_eq!(around.len(), 8);
+        assert!(around.iter().all(|x| x.chebyshev(p) == 1));
+
+        let p = Point3::new(1, 1, 1);
+        assert_eq!(p.orthogonal().filter(|x| x.manhattan(p) == 1).count(), 6);
+        let around: HashSet<_> = p.neighbours().collect();
+        assert_eq!(around.len(), 26);
+        assert!(!around.contains(&p));
+    }
+}
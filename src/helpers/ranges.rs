@@ -0,0 +1,245 @@
+use std::fmt;
+use std::ops::{Add, Range, RangeInclusive, Sub};
+
+/// Integers that can be the ends of a range.
+pub trait Integer: Copy + Ord + Add<Output = Self> + Sub<Output = Self> {
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! integer {
+    ($($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$t>::checked_add(self, other)
+                }
+            }
+        )*
+    };
+}
+
+integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// The half open span holding `range`, it ends one past the last value.
+fn span_of<T: Integer>(range: RangeInclusive<T>) -> Range<T> {
+    let (start, end) = range.into_inner();
+    let end = end
+        .checked_add(T::ONE)
+        .expect("a RangeSet can't hold the largest value of its type");
+    start..end
+}
+
+/// A set of integers kept as sorted spans, so it stays small for long runs of values like the
+/// columns a sensor covers.
+///
+/// The spans are half open like `Range`, never empty and never touch each other, `1..3` and
+/// `3..5` are stored as `1..5`. That leaves out the largest value of `T`, inclusive ranges
+/// ending there panic.
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct RangeSet<T> {
+    spans: Vec<Range<T>>,
+}
+
+impl<T: Integer> RangeSet<T> {
+    pub fn new() -> Self {
+        RangeSet { spans: Vec::new() }
+    }
+
+    /// Sorts and merges `spans` into the form `RangeSet` keeps them in.
+    fn normalize(mut spans: Vec<Range<T>>) -> Self {
+        spans.retain(|x| x.start < x.end);
+        spans.sort_unstable_by_key(|x| x.start);
+        let mut merged: Vec<Range<T>> = Vec::with_capacity(spans.len());
+        for span in spans {
+            match merged.last_mut() {
+                Some(last) if span.start <= last.end => last.end = last.end.max(span.end),
+                _ => merged.push(span),
+            }
+        }
+        RangeSet { spans: merged }
+    }
+
+    pub fn insert(&mut self, range: Range<T>) {
+        let mut spans = std::mem::take(&mut self.spans);
+        spans.push(range);
+        *self = Self::normalize(spans);
+    }
+
+    pub fn remove(&mut self, range: Range<T>) {
+        *self = self.difference(&RangeSet::from(range));
+    }
+
+    /// The merged spans in increasing order.
+    pub fn iter(&self) -> impl Iterator<Item = Range<T>> + '_ {
+        self.spans.iter().cloned()
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        // the last span starting at or before `value`
+        let i = self.spans.partition_point(|x| x.start <= value);
+        i > 0 && value < self.spans[i - 1].end
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.spans.is_empty()
+    }
+
+    /// Number of values in the set.
+    pub fn len(&self) -> T {
+        self.spans
+            .iter()
+            .fold(T::ZERO, |total, x| total + (x.end - x.start))
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        Self::normalize(self.iter().chain(other.iter()).collect())
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut spans = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while let (Some(a), Some(b)) = (self.spans.get(i), other.spans.get(j)) {
+            let (start, end) = (a.start.max(b.start), a.end.min(b.end));
+            if start < end {
+                spans.push(start..end);
+            }
+            // the span that ends first can't overlap anything after the other one
+            match a.end < b.end {
+                true => i += 1,
+                false => j += 1,
+            }
+        }
+        RangeSet { spans }
+    }
+
+    /// Values of `self` that are not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut spans = Vec::new();
+        let mut j = 0;
+        for span in self.spans.iter() {
+            let mut start = span.start;
+            // skip what ends before this span, it can't end before any later span either
+            while other.spans.get(j).is_some_and(|x| x.end <= start) {
+                j += 1;
+            }
+            let mut k = j;
+            while let Some(cut) = other.spans.get(k).filter(|x| x.start < span.end) {
+                if start < cut.start {
+                    spans.push(start..cut.start);
+                }
+                start = start.max(cut.end);
+                k += 1;
+            }
+            if start < span.end {
+                spans.push(start..span.end);
+            }
+        }
+        RangeSet { spans }
+    }
+
+    pub fn is_superset(&self, other: &Self) -> bool {
+        other.difference(self).is_empty()
+    }
+
+    pub fn is_subset(&self, other: &Self) -> bool {
+        other.is_superset(self)
+    }
+
+    /// Whether any value is in both sets.
+    pub fn overlaps(&self, other: &Self) -> bool {
+        !self.intersection(other).is_empty()
+    }
+}
+
+impl<T: Integer> From<Range<T>> for RangeSet<T> {
+    fn from(range: Range<T>) -> Self {
+        Self::normalize(vec![range])
+    }
+}
+
+impl<T: Integer> From<RangeInclusive<T>> for RangeSet<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        Self::normalize(vec![span_of(range)])
+    }
+}
+
+impl<T: Integer> FromIterator<Range<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        Self::normalize(iter.into_iter().collect())
+    }
+}
+
+impl<T: Integer> FromIterator<RangeInclusive<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> Self {
+        iter.into_iter().map(span_of).collect()
+    }
+}
+
+/// Like a set of its spans, `{1..5, 7..8}`.
+impl<T: fmt::Debug> fmt::Debug for RangeSet<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.spans.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert() {
+        let mut set = RangeSet::new();
+        set.insert(5..8);
+        set.insert(1..3);
+        set.insert(3..4);
+        set.insert(10..10);
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![1..4, 5..8]);
+        assert_eq!(set.len(), 6);
+        assert!(set.contains(1) && set.contains(7));
+        assert!(!set.contains(4) && !set.contains(8) && !set.contains(0));
+
+        set.insert(2..6);
+        assert_eq!(set, RangeSet::from(1..=7));
+        set.remove(3..5);
+        assert_eq!(set, [1..3, 5..8].into_iter().collect());
+        assert!(!RangeSet::<u8>::new().contains(0));
+    }
+
+    #[test]
+    fn test_operations() {
+        let a: RangeSet<i32> = [-5..=0, 3..=9].into_iter().collect();
+        let b: RangeSet<i32> = [-2..=4, 8..=20].into_iter().collect();
+        assert_eq!(a.union(&b), RangeSet::from(-5..=20));
+        assert_eq!(
+            a.intersection(&b),
+            [-2..=0, 3..=4, 8..=9].into_iter().collect()
+        );
+        assert_eq!(a.difference(&b), [-5..-2, 5..8].into_iter().collect());
+        assert_eq!(b.difference(&a), [1..3, 10..21].into_iter().collect());
+        assert_eq!(a.difference(&a), RangeSet::new());
+    }
+
+    #[test]
+    fn test_relations() {
+        let outer = RangeSet::from(2..=8);
+        let inner = RangeSet::from(3..=7);
+        let touching = RangeSet::from(8..=9);
+        assert!(outer.is_superset(&inner) && inner.is_subset(&outer));
+        assert!(!inner.is_superset(&outer));
+        assert!(outer.overlaps(&touching) && !inner.overlaps(&touching));
+        assert_eq!(format!("{:?}", outer.union(&touching)), "{2..10}");
+    }
+
+    #[test]
+    #[should_panic(expected = "largest value")]
+    fn test_largest_value() {
+        assert_eq!(RangeSet::from(0..=254_u8).len(), 255);
+        let _ = RangeSet::from(0..=u8::MAX);
+    }
+}
@@ -0,0 +1,211 @@
+use hashbrown::hash_map::Entry;
+use hashbrown::HashMap;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// The cheapest way to a goal, `nodes` runs from the start that was used to the goal.
+///
+/// The searches walk graphs given by a successor function, like the positions of a grid or the
+/// states of a puzzle, and take several starts at once. That is the same as one start with a
+/// free step to each of them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<N, C> {
+    pub cost: C,
+    pub nodes: Vec<N>,
+}
+
+/// Nodes found so far, each with the index of the node it was reached from.
+struct Tree<N> {
+    nodes: Vec<(N, Option<usize>)>,
+    index: HashMap<N, usize>,
+}
+
+impl<N: Eq + Hash + Clone> Tree<N> {
+    fn new() -> Self {
+        Tree {
+            nodes: Vec::new(),
+            index: HashMap::new(),
+        }
+    }
+
+    fn push(&mut self, node: N, parent: Option<usize>) -> usize {
+        self.index.insert(node.clone(), self.nodes.len());
+        self.nodes.push((node, parent));
+        self.nodes.len() - 1
+    }
+
+    fn path<C>(&self, mut i: usize, cost: C) -> Path<N, C> {
+        let mut nodes = vec![self.nodes[i].0.clone()];
+        while let Some(parent) = self.nodes[i].1 {
+            nodes.push(self.nodes[parent].0.clone());
+            i = parent;
+        }
+        nodes.reverse();
+        Path { cost, nodes }
+    }
+}
+
+/// Breadth first search where every step costs 1, the cost is the number of steps.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+    mut goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, usize>>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut tree = Tree::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if !tree.index.contains_key(&start) {
+            queue.push_back((tree.push(start, None), 0));
+        }
+    }
+
+    while let Some((i, steps)) = queue.pop_front() {
+        if goal(&tree.nodes[i].0) {
+            return Some(tree.path(i, steps));
+        }
+        for next in successors(&tree.nodes[i].0) {
+            if !tree.index.contains_key(&next) {
+                queue.push_back((tree.push(next, Some(i)), steps + 1));
+            }
+        }
+    }
+    None
+}
+
+/// Dijkstra's algorithm, `successors` gives the neighbours of a node with the cost of getting
+/// there. Costs can't be negative.
+pub fn dijkstra<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    successors: impl FnMut(&N) -> I,
+    goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(starts, successors, |_| C::default(), goal)
+}
+
+/// A* search, Dijkstra guided by a `heuristic` that estimates the cost left to a goal. The path
+/// is only the cheapest one if the heuristic never overestimates.
+pub fn astar<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut tree = Tree::new();
+    // best known cost of every node in `tree`
+    let mut costs = Vec::new();
+    let mut heap = BinaryHeap::new();
+    for start in starts {
+        if !tree.index.contains_key(&start) {
+            let estimate = heuristic(&start);
+            heap.push(Reverse((estimate, C::default(), tree.push(start, None))));
+            costs.push(C::default());
+        }
+    }
+
+    while let Some(Reverse((_, cost, i))) = heap.pop() {
+        // a cheaper way to this node was queued after this one
+        if cost > costs[i] {
+            continue;
+        }
+        if goal(&tree.nodes[i].0) {
+            return Some(tree.path(i, cost));
+        }
+        for (next, step) in successors(&tree.nodes[i].0) {
+            let next_cost = cost + step;
+            let j = match tree.index.entry(next) {
+                Entry::Occupied(x) => {
+                    let j = *x.get();
+                    if costs[j] <= next_cost {
+                        continue;
+                    }
+                    costs[j] = next_cost;
+                    tree.nodes[j].1 = Some(i);
+                    j
+                }
+                Entry::Vacant(x) => {
+                    let next = x.key().clone();
+                    x.insert(tree.nodes.len());
+                    tree.nodes.push((next, Some(i)));
+                    costs.push(next_cost);
+                    tree.nodes.len() - 1
+                }
+            };
+            let estimate = next_cost + heuristic(&tree.nodes[j].0);
+            heap.push(Reverse((estimate, next_cost, j)));
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// From 2 the graph splits, a cheap long way over 3, 4 and 5 and an expensive edge to 5.
+    fn edges(node: &u32) -> Vec<(u32, u32)> {
+        match node {
+            1 => vec![(2, 1)],
+            2 => vec![(3, 1), (5, 10)],
+            3 => vec![(4, 1)],
+            4 => vec![(5, 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_bfs() {
+        let path = bfs([1], |x| edges(x).into_iter().map(|(x, _)| x), |x| *x == 5).unwrap();
+        assert_eq!(path.nodes, vec![1, 2, 5]);
+        assert_eq!(path.cost, 2);
+
+        let path = bfs(
+            [1, 4],
+            |x| edges(x).into_iter().map(|(x, _)| x),
+            |x| *x == 5,
+        )
+        .unwrap();
+        assert_eq!(path.nodes, vec![4, 5]);
+        assert_eq!(bfs([5], |_| [1], |x| *x == 7), None);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let path = dijkstra([1], edges, |x| *x == 5).unwrap();
+        assert_eq!(path.nodes, vec![1, 2, 3, 4, 5]);
+        assert_eq!(path.cost, 4);
+        assert_eq!(dijkstra([3, 2], edges, |x| *x == 5).unwrap().cost, 2);
+        assert_eq!(dijkstra([5], edges, |x| *x == 1), None);
+    }
+
+    #[test]
+    fn test_astar() {
+        // a grid without walls, the Manhattan distance is exact
+        let goal = (3, -2);
+        let path = astar(
+            [(0, 0)],
+            |&(x, y): &(i32, i32)| [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)].map(|p| (p, 1)),
+            |&(x, y)| (goal.0 - x).abs() + (goal.1 - y).abs(),
+            |x| *x == goal,
+        )
+        .unwrap();
+        assert_eq!(path.cost, 5);
+        assert_eq!(path.nodes.len(), 6);
+        assert_eq!(path.nodes.last(), Some(&goal));
+    }
+}
@@ -0,0 +1,297 @@
+/*
+ * This file contains template code.
+ * Timings of `cargo all --bench` runs, keyed by git commit, and regression checks between them.
+ */
+use crate::{Error, Report, Status};
+use std::collections::BTreeMap;
+use std::process::Command;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use std::{fs, io};
+use toml::Value;
+
+pub const FILE: &str = "bench_history.toml";
+
+/// Differences below this are noise for parts that run in nanoseconds.
+const NOISE_FLOOR: Duration = Duration::from_micros(1);
+
+/// Median times of one run of a year, per day and part. Parts that weren't solved have no time.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Run {
+    pub year: u32,
+    /// seconds since the unix epoch
+    pub recorded: u64,
+    pub days: BTreeMap<u8, [Option<Duration>; 2]>,
+}
+
+impl Run {
+    pub fn new(year: u32) -> Self {
+        Run {
+            year,
+            recorded: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |x| x.as_secs()),
+            days: BTreeMap::new(),
+        }
+    }
+
+    pub fn add(&mut self, day: u8, report: &Report) {
+        let times = report.parts.each_ref().map(|part| match part.status {
+            Status::Solved(_) => Some(part.time()),
+            _ => None,
+        });
+        self.days.insert(day, times);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Regression {
+    pub day: u8,
+    pub part: u8,
+    pub before: Duration,
+    pub after: Duration,
+}
+
+impl Regression {
+    /// slowdown in percent
+    pub fn percent(&self) -> f64 {
+        (self.after.as_secs_f64() / self.before.as_secs_f64() - 1_f64) * 100_f64
+    }
+}
+
+/// Parts of `current` that got slower than `baseline` by more than `threshold` percent.
+pub fn regressions(baseline: &Run, current: &Run, threshold: f64) -> Vec<Regression> {
+    let mut regressions = Vec::new();
+    for (day, times) in current.days.iter() {
+        let Some(before) = baseline.days.get(day) else {
+            continue;
+        };
+        for (part, (before, after)) in before.iter().zip(times.iter()).enumerate() {
+            let (Some(before), Some(after)) = (*before, *after) else {
+                continue;
+            };
+            if after.saturating_sub(before) > NOISE_FLOOR
+                && after.as_secs_f64() > before.as_secs_f64() * (1_f64 + threshold / 100_f64)
+            {
+                regressions.push(Regression {
+                    day: *day,
+                    part: part as u8 + 1,
+                    before,
+                    after,
+                });
+            }
+        }
+    }
+    regressions
+}
+
+/// Runs keyed by year and commit, e.g.
+/// ```toml
+/// [2022-ac54541d8a0e4f9a6d3bdb2fc3ab4d2a0b1d4b7e]
+/// year = 2022
+/// recorded = 1670000000
+///
+/// [2022-ac54541d8a0e4f9a6d3bdb2fc3ab4d2a0b1d4b7e.01]
+/// part_one = 5312
+/// part_two = 10468
+/// ```
+#[derive(Debug, Default)]
+pub struct History(BTreeMap<String, Run>);
+
+fn nanos(value: &Value, key: &str) -> Result<Option<Duration>, String> {
+    match value.get(key) {
+        None => Ok(None),
+        Some(Value::Integer(x)) if *x >= 0 => Ok(Some(Duration::from_nanos(*x as u64))),
+        Some(_) => Err(format!("`{key}` should be a number of nanoseconds")),
+    }
+}
+
+impl History {
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let table = s.parse::<Value>().map_err(|e| e.to_string())?;
+        let table = table.as_table().ok_or("expected a table")?;
+
+        let mut runs = BTreeMap::new();
+        for (key, entry) in table {
+            let entry = entry
+                .as_table()
+                .ok_or(format!("`{key}` should be a table"))?;
+            // runs from before the multi-year layout have no year
+            let mut run = Run {
+                year: crate::DEFAULT_YEAR,
+                ..Run::default()
+            };
+            for (field, value) in entry {
+                if field == "year" {
+                    run.year = value
+                        .as_integer()
+                        .ok_or(format!("`{key}.year` should be an integer"))?
+                        as u32;
+                    continue;
+                }
+                if field == "recorded" {
+                    run.recorded = value
+                        .as_integer()
+                        .ok_or(format!("`{key}.recorded` should be an integer"))?
+                        as u64;
+                    continue;
+                }
+                let day = field
+                    .parse::<u8>()
+                    .map_err(|_| format!("`{key}.{field}` is not a day"))?;
+                let times = [nanos(value, "part_one")?, nanos(value, "part_two")?];
+                run.days.insert(day, times);
+            }
+            runs.insert(key.clone(), run);
+        }
+        Ok(History(runs))
+    }
+
+    pub fn to_toml(&self) -> String {
+        let mut table = toml::value::Table::new();
+        for (key, run) in self.0.iter() {
+            let mut entry = toml::value::Table::new();
+            entry.insert("year".to_string(), Value::Integer(run.year as i64));
+            entry.insert("recorded".to_string(), Value::Integer(run.recorded as i64));
+            for (day, times) in run.days.iter() {
+                let mut parts = toml::value::Table::new();
+                for (name, time) in ["part_one", "part_two"].iter().zip(times) {
+                    if let Some(time) = time {
+                        parts.insert(name.to_string(), Value::Integer(time.as_nanos() as i64));
+                    }
+                }
+                entry.insert(format!("{day:02}"), Value::Table(parts));
+            }
+            table.insert(key.clone(), Value::Table(entry));
+        }
+        toml::to_string(&Value::Table(table)).expect("history is always valid toml")
+    }
+
+    /// Reads the history from the crate root; a missing file is an empty history.
+    pub fn load() -> crate::Result<Self> {
+        let path = crate::root().join(FILE);
+        match fs::read_to_string(&path) {
+            Ok(s) => History::parse(&s).map_err(|message| Error::Malformed { path, message }),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(History::default()),
+            Err(e) => Err(Error::io(path, e)),
+        }
+    }
+
+    pub fn save(&self) -> crate::Result<()> {
+        let path = crate::root().join(FILE);
+        fs::write(&path, self.to_toml()).map_err(|e| Error::io(path, e))
+    }
+
+    /// Most recently recorded run of `year` other than `key`, the baseline for a run stored under `key`.
+    pub fn baseline(&self, key: &str, year: u32) -> Option<(&str, &Run)> {
+        self.0
+            .iter()
+            .filter(|(k, run)| k.as_str() != key && run.year == year)
+            .max_by_key(|(_, run)| run.recorded)
+            .map(|(k, run)| (k.as_str(), run))
+    }
+
+    /// Replaces an earlier run of the same commit.
+    pub fn insert(&mut self, key: String, run: Run) {
+        self.0.insert(key, run);
+    }
+}
+
+/// Key for a new run: its year and the checked out commit, marked when the tree has uncommitted
+/// changes. Outside of a git repository every run gets its own key.
+pub fn run_key(run: &Run) -> String {
+    format!("{}-{}", run.year, revision(run))
+}
+
+fn revision(run: &Run) -> String {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .current_dir(crate::root())
+            .output()
+            .ok()
+    };
+    match git(&["rev-parse", "HEAD"]) {
+        Some(output) if output.status.success() => {
+            let commit = String::from_utf8_lossy(&output.stdout).trim().to_string();
+            match git(&["status", "--porcelain"]) {
+                Some(status) if !status.stdout.is_empty() => format!("{commit}-dirty"),
+                _ => commit,
+            }
+        }
+        _ => format!("run-{}", run.recorded),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(recorded: u64, days: &[(u8, [Option<u64>; 2])]) -> Run {
+        Run {
+            year: 2022,
+            recorded,
+            days: days
+                .iter()
+                .map(|(day, times)| (*day, times.map(|x| x.map(Duration::from_micros))))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let mut history = History::default();
+        history.insert("abc".to_string(), run(1, &[(1, [Some(5), None])]));
+        history.insert("def".to_string(), run(2, &[(16, [Some(300), Some(900)])]));
+
+        let parsed = History::parse(&history.to_toml()).unwrap();
+        assert_eq!(parsed.0, history.0);
+        assert!(History::parse("[abc]\nrecorded = 1\n[abc.first]\npart_one = 1").is_err());
+        let old = History::parse("[abc]\nrecorded = 1\n[abc.01]\npart_one = 5000").unwrap();
+        assert_eq!(old.0["abc"].year, 2022);
+    }
+
+    #[test]
+    fn test_baseline() {
+        let mut history = History::default();
+        assert_eq!(history.baseline("abc", 2022), None);
+        history.insert("abc".to_string(), run(1, &[]));
+        history.insert("def".to_string(), run(3, &[]));
+        history.insert("ghi".to_string(), run(2, &[]));
+        history.insert(
+            "jkl".to_string(),
+            Run {
+                year: 2021,
+                ..run(4, &[])
+            },
+        );
+        assert_eq!(history.baseline("xyz", 2022).map(|x| x.0), Some("def"));
+        assert_eq!(history.baseline("def", 2022).map(|x| x.0), Some("ghi"));
+        assert_eq!(history.baseline("xyz", 2021).map(|x| x.0), Some("jkl"));
+        assert_eq!(history.baseline("jkl", 2021), None);
+    }
+
+    #[test]
+    fn test_regressions() {
+        let baseline = run(1, &[(1, [Some(100), Some(100)]), (2, [Some(1), None])]);
+        let current = run(
+            2,
+            &[
+                (1, [Some(105), Some(150)]),
+                (2, [Some(1), Some(50)]),
+                (3, [Some(9), Some(9)]),
+            ],
+        );
+        let found = regressions(&baseline, &current, 10_f64);
+        assert_eq!(
+            found,
+            vec![Regression {
+                day: 1,
+                part: 2,
+                before: Duration::from_micros(100),
+                after: Duration::from_micros(150),
+            }]
+        );
+        assert_eq!(found[0].percent().round(), 50_f64);
+        assert_eq!(regressions(&baseline, &current, 1_f64).len(), 2);
+    }
+}
@@ -0,0 +1,115 @@
+/*
+ * This file contains template code.
+ * Runs solutions on their own thread so a panic or a hang can't take the runner down.
+ */
+use std::any::Any;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Solutions recurse deeply, the default 2MiB of a spawned thread is not enough.
+const STACK_SIZE: usize = 256 * 1024 * 1024;
+
+/// Limits from `--timeout` (parse and each part) and `--day-timeout` (all of them together).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Timeouts {
+    pub part: Option<Duration>,
+    pub day: Option<Duration>,
+}
+
+impl Timeouts {
+    /// Time the next step may take when `spent` of the day's budget is used up.
+    pub fn remaining(&self, spent: Duration) -> Option<Duration> {
+        let day = self.day.map(|x| x.saturating_sub(spent));
+        match (self.part, day) {
+            (Some(part), Some(day)) => Some(part.min(day)),
+            (part, day) => part.or(day),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Outcome<T> {
+    Done(T, Duration),
+    Panicked(String),
+    TimedOut,
+}
+
+pub fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic payload".to_string()
+    }
+}
+
+/// Runs `f` on a new thread and waits at most `timeout` for it. A thread that times out
+/// can't be killed, it is left running in the background.
+pub fn isolate<T: Send + 'static>(
+    timeout: Option<Duration>,
+    f: impl FnOnce() -> T + Send + 'static,
+) -> Outcome<T> {
+    let (sender, receiver) = mpsc::channel();
+    let handle = thread::Builder::new()
+        .stack_size(STACK_SIZE)
+        .spawn(move || {
+            let timer = Instant::now();
+            let result = f();
+            // the receiver is gone if we already timed out
+            let _ = sender.send((result, timer.elapsed()));
+        })
+        .expect("could not spawn solution thread");
+
+    let received = match timeout {
+        Some(timeout) => receiver.recv_timeout(timeout),
+        None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+    };
+    match received {
+        Ok((result, elapsed)) => Outcome::Done(result, elapsed),
+        Err(RecvTimeoutError::Timeout) => Outcome::TimedOut,
+        // the sender only disconnects without sending if `f` panicked
+        Err(RecvTimeoutError::Disconnected) => match handle.join() {
+            Err(payload) => Outcome::Panicked(panic_message(payload)),
+            Ok(()) => unreachable!(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_remaining() {
+        let secs = Duration::from_secs;
+        let timeouts = Timeouts {
+            part: Some(secs(2)),
+            day: Some(secs(5)),
+        };
+        assert_eq!(timeouts.remaining(secs(0)), Some(secs(2)));
+        assert_eq!(timeouts.remaining(secs(4)), Some(secs(1)));
+        assert_eq!(timeouts.remaining(secs(6)), Some(Duration::ZERO));
+        assert_eq!(Timeouts::default().remaining(secs(6)), None);
+    }
+
+    #[test]
+    fn test_isolate() {
+        assert!(matches!(isolate(None, || 7), Outcome::Done(7, _)));
+        assert!(matches!(
+            isolate(None, || -> u8 { panic!("at the disco") }),
+            Outcome::Panicked(message) if message == "at the disco"
+        ));
+        assert!(matches!(
+            isolate(None, || -> u8 { panic!("{} disco", "at the") }),
+            Outcome::Panicked(message) if message == "at the disco"
+        ));
+        assert!(matches!(
+            isolate(Some(Duration::from_millis(10)), || thread::sleep(
+                Duration::from_secs(1)
+            )),
+            Outcome::TimedOut
+        ));
+    }
+}
@@ -1,127 +1,696 @@
 /*
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
- * Prefer `./helpers.rs` if you want to extract code from your solutions.
+ * Prefer `./helpers/` if you want to extract code from your solutions.
  */
-use std::env;
+use answers::{Answers, Check};
+use bench::Stats;
+use std::fmt::Display;
 use std::fs;
+use std::hint::black_box;
+use std::io::{self, Read};
+use std::path::PathBuf;
+use std::process;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+
+pub mod answers;
+pub mod bench;
+pub mod client;
+pub mod download;
+pub mod error;
+pub mod helpers;
+pub mod history;
+pub mod isolate;
+pub mod puzzle;
+pub mod readme;
+
+pub use error::{exit, Error, Result};
+use helpers::parser::ParseError;
+use isolate::{isolate, Outcome, Timeouts};
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
-#[macro_export]
-macro_rules! solve {
-    ($part:expr, $solver:ident, $input:expr) => {{
-        use aoc::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-        use std::fmt::Display;
-        use std::time::Instant;
-
-        fn print_result<T: Display>(func: impl FnOnce(&str) -> Option<T>, input: &str) {
-            let timer = Instant::now();
-            let result = func(input);
-            let elapsed = timer.elapsed();
-            match result {
-                Some(result) => {
-                    println!(
-                        "{} {}(elapsed: {:.2?}){}",
-                        result, ANSI_ITALIC, elapsed, ANSI_RESET
-                    );
-                }
-                None => {
-                    println!("not solved.")
-                }
+/// A day's solution; the input is parsed once and shared by both parts.
+/// Every step runs on its own thread, hence the bounds.
+pub trait Solution: 'static {
+    type Parsed: Send + Sync;
+    type PartOne: Display;
+    type PartTwo: Display;
+
+    fn parse(input: &str) -> Self::Parsed;
+
+    /// Like [`Solution::parse`] but reports malformed input instead of panicking, the runners
+    /// call this one. Days that parse with [`helpers::parser`] implement it and have `parse`
+    /// return [`parse_or_panic`] of it.
+    fn try_parse(input: &str) -> std::result::Result<Self::Parsed, ParseError> {
+        Ok(Self::parse(input))
+    }
+
+    fn part_one(parsed: &Self::Parsed) -> Option<Self::PartOne>;
+    fn part_two(parsed: &Self::Parsed) -> Option<Self::PartTwo>;
+}
+
+/// The parsed input of [`Solution::try_parse`], panicking with the error when the input is
+/// malformed.
+pub fn parse_or_panic<T>(parsed: std::result::Result<T, ParseError>) -> T {
+    parsed.unwrap_or_else(|e| panic!("{e}"))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Solved(String),
+    /// the part returned `None`
+    Unsolved,
+    Panicked(String),
+    TimedOut,
+    /// the input could not be parsed, see [`Solution::try_parse`]
+    Invalid(String),
+}
+
+impl Status {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Status::Solved(_) => "solved",
+            Status::Unsolved => "unsolved",
+            Status::Panicked(_) => "panicked",
+            Status::TimedOut => "timed_out",
+            Status::Invalid(_) => "invalid",
+        }
+    }
+
+    /// Whether the part broke instead of giving an answer or `None`.
+    pub fn is_failure(&self) -> bool {
+        matches!(
+            self,
+            Status::Panicked(_) | Status::TimedOut | Status::Invalid(_)
+        )
+    }
+
+    pub fn answer(&self) -> Option<&str> {
+        match self {
+            Status::Solved(answer) => Some(answer),
+            _ => None,
+        }
+    }
+}
+
+pub struct Part {
+    pub part: u8,
+    pub status: Status,
+    pub elapsed: Duration,
+    /// only filled in with `--bench`
+    pub stats: Option<Stats>,
+}
+
+impl Part {
+    fn new(part: u8, status: Status, elapsed: Duration) -> Self {
+        Part {
+            part,
+            status,
+            elapsed,
+            stats: None,
+        }
+    }
+
+    /// median when benchmarked, single run time otherwise
+    pub fn time(&self) -> Duration {
+        self.stats.as_ref().map_or(self.elapsed, |x| x.median)
+    }
+}
+
+pub struct Report {
+    pub parse: Duration,
+    pub parse_stats: Option<Stats>,
+    pub parts: [Part; 2],
+}
+
+impl Report {
+    /// Report for a day that could not be run, e.g. because its input is missing.
+    pub fn unsolved() -> Self {
+        Report {
+            parse: Duration::ZERO,
+            parse_stats: None,
+            parts: [1, 2].map(|part| Part::new(part, Status::Unsolved, Duration::ZERO)),
+        }
+    }
+
+    pub fn parse_time(&self) -> Duration {
+        self.parse_stats.as_ref().map_or(self.parse, |x| x.median)
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        self.parse_time() + self.parts.iter().map(Part::time).sum::<Duration>()
+    }
+
+    /// Number of parts that panicked, timed out or got invalid input.
+    pub fn failures(&self) -> usize {
+        self.parts.iter().filter(|x| x.status.is_failure()).count()
+    }
+}
+
+/// Entry of the day registry used by the all-days runner.
+pub struct Day {
+    pub year: u32,
+    pub day: u8,
+    pub run: fn(&str, &Timeouts) -> Report,
+    pub bench: fn(&str, &Timeouts, &bench::Config) -> Report,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("unknown format `{s}`, expected `text` or `json`")),
+        }
+    }
+}
+
+/// Command line options shared by `cargo solve` and `cargo all`.
+pub struct Options {
+    pub format: Format,
+    /// set with `--bench`
+    pub bench: Option<bench::Config>,
+    /// number of days `cargo all` runs at once
+    pub jobs: usize,
+    pub timeouts: Timeouts,
+    /// slowdown in percent that `cargo all --bench` reports as a regression
+    pub threshold: f64,
+    /// `--input <path>` replaces `src/inputs/YEAR/NN.txt`, `-` reads stdin
+    pub input: Option<PathBuf>,
+    /// `--year`, see [`Options::year`]
+    pub year: Option<u32>,
+}
+
+/// Fails on whatever is left of `args` once everything known was taken, like a misspelled flag.
+pub fn finish_args(args: pico_args::Arguments) -> Result<()> {
+    let unused: Vec<_> = args
+        .finish()
+        .iter()
+        .map(|x| format!("`{}`", x.to_string_lossy()))
+        .collect();
+    match unused.len() {
+        0 => Ok(()),
+        1 => Err(Error::BadArgument(format!(
+            "Unknown argument {}.",
+            unused[0]
+        ))),
+        _ => Err(Error::BadArgument(format!(
+            "Unknown arguments {}.",
+            unused.join(", ")
+        ))),
+    }
+}
+
+fn parse_seconds(s: &str) -> Result<Duration, String> {
+    Duration::try_from_secs_f64(
+        s.parse()
+            .map_err(|_| format!("`{s}` is not a number of seconds"))?,
+    )
+    .map_err(|e| e.to_string())
+}
+
+impl Options {
+    pub fn parse(args: &mut pico_args::Arguments) -> Result<Self> {
+        let format = args.opt_value_from_str("--format")?.unwrap_or(Format::Text);
+
+        let mut config = bench::Config::default();
+        let enabled = args.contains("--bench");
+        if let Some(warmup) = args.opt_value_from_str("--warmup")? {
+            config.warmup = warmup;
+        }
+        if let Some(samples) = args.opt_value_from_str("--samples")? {
+            config.max_samples = samples;
+        }
+
+        Ok(Options {
+            format,
+            bench: enabled.then_some(config),
+            jobs: args.opt_value_from_str("--jobs")?.unwrap_or(1),
+            timeouts: Timeouts {
+                part: args.opt_value_from_fn("--timeout", parse_seconds)?,
+                day: args.opt_value_from_fn("--day-timeout", parse_seconds)?,
+            },
+            threshold: args.opt_value_from_str("--threshold")?.unwrap_or(10_f64),
+            input: args.opt_value_from_os_str("--input", |x| {
+                Ok::<_, std::convert::Infallible>(PathBuf::from(x))
+            })?,
+            year: args.opt_value_from_str("--year")?,
+        })
+    }
+
+    /// The year to run, `--year` or else [`default_year`].
+    pub fn year(&self) -> u32 {
+        self.year.unwrap_or_else(default_year)
+    }
+
+    /// Input of `day`, from `--input` if given.
+    pub fn read_input(&self, year: u32, day: u8) -> Result<String> {
+        let (path, read) = match &self.input {
+            Some(path) if path.as_os_str() == "-" => {
+                let mut input = String::new();
+                let read = io::stdin().read_to_string(&mut input).map(|_| input);
+                (PathBuf::from("stdin"), read)
             }
+            Some(path) => (path.clone(), fs::read_to_string(path)),
+            None => return read_file("inputs", Some(year), day),
+        };
+        read.map_err(|source| Error::MissingInput { path, source })
+    }
+
+    /// Pinned answers belong to `src/inputs`, any other input is not verified.
+    pub fn answers(&self, year: u32) -> Result<Answers> {
+        match self.input {
+            Some(_) => Ok(Answers::default()),
+            None => Answers::load(year),
         }
+    }
 
-        println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, $part, ANSI_RESET);
-        print_result($solver, $input);
-    }};
+    pub fn run(&self, day: &Day, input: &str) -> Report {
+        match &self.bench {
+            Some(config) => (day.bench)(input, &self.timeouts, config),
+            None => (day.run)(input, &self.timeouts),
+        }
+    }
+}
+
+fn run_part(
+    part: u8,
+    timeout: Option<Duration>,
+    f: impl FnOnce() -> Option<String> + Send + 'static,
+) -> Part {
+    match isolate(timeout, f) {
+        Outcome::Done(Some(answer), elapsed) => Part::new(part, Status::Solved(answer), elapsed),
+        Outcome::Done(None, elapsed) => Part::new(part, Status::Unsolved, elapsed),
+        Outcome::Panicked(message) => Part::new(part, Status::Panicked(message), Duration::ZERO),
+        Outcome::TimedOut => Part::new(part, Status::TimedOut, timeout.unwrap_or_default()),
+    }
 }
 
-pub fn read_file(folder: &str, day: u8) -> String {
-    let cwd = env::current_dir().unwrap();
+fn run_parsed<S: Solution>(input: &str, timeouts: &Timeouts) -> (Report, Option<Arc<S::Parsed>>) {
+    let input = input.to_string();
+    let (parsed, parse) = match isolate(timeouts.remaining(Duration::ZERO), move || {
+        S::try_parse(&input)
+    }) {
+        Outcome::Done(Ok(parsed), elapsed) => (Ok(Arc::new(parsed)), elapsed),
+        Outcome::Done(Err(e), _) => (Err(Status::Invalid(e.to_string())), Duration::ZERO),
+        // neither part can run without parsed input
+        Outcome::Panicked(message) => (Err(Status::Panicked(message)), Duration::ZERO),
+        Outcome::TimedOut => (Err(Status::TimedOut), Duration::ZERO),
+    };
 
-    let filepath = cwd.join("src").join(folder).join(format!("{day:02}.txt"));
+    let parts = match &parsed {
+        Ok(parsed) => {
+            let one = Arc::clone(parsed);
+            let part_one = run_part(1, timeouts.remaining(parse), move || {
+                S::part_one(&one).map(|x| x.to_string())
+            });
+            let two = Arc::clone(parsed);
+            let part_two = run_part(2, timeouts.remaining(parse + part_one.elapsed), move || {
+                S::part_two(&two).map(|x| x.to_string())
+            });
+            [part_one, part_two]
+        }
+        Err(status) => [1, 2].map(|part| Part::new(part, status.clone(), Duration::ZERO)),
+    };
+    let report = Report {
+        parse,
+        parse_stats: None,
+        parts,
+    };
+    (report, parsed.ok())
+}
 
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+pub fn run<S: Solution>(input: &str, timeouts: &Timeouts) -> Report {
+    run_parsed::<S>(input, timeouts).0
 }
 
-fn parse_time(val: &str, postfix: &str) -> f64 {
-    val.split(postfix).next().unwrap().parse().unwrap()
+/// Runs once like [`run`], then samples parsing and every solved part.
+pub fn bench<S: Solution>(input: &str, timeouts: &Timeouts, config: &bench::Config) -> Report {
+    let (mut report, parsed) = run_parsed::<S>(input, timeouts);
+    let Some(parsed) = parsed else {
+        return report;
+    };
+
+    let solved = report
+        .parts
+        .each_ref()
+        .map(|x| matches!(x.status, Status::Solved(_)));
+    let input = input.to_string();
+    let config = config.clone();
+    // the single run finished in time, so sampling is only bounded by the bench budget
+    let sampled = isolate(None, move || {
+        let parse = bench::sample(&config, || {
+            let _ = black_box(S::try_parse(black_box(&input)));
+        });
+        let part_one = solved[0].then(|| {
+            bench::sample(&config, || {
+                black_box(S::part_one(black_box(&parsed)));
+            })
+        });
+        let part_two = solved[1].then(|| {
+            bench::sample(&config, || {
+                black_box(S::part_two(black_box(&parsed)));
+            })
+        });
+        (parse, [part_one, part_two])
+    });
+
+    if let Outcome::Done((parse, parts), _) = sampled {
+        report.parse_stats = Some(parse);
+        for (part, stats) in report.parts.iter_mut().zip(parts) {
+            part.stats = stats;
+        }
+    }
+    report
 }
 
-pub fn parse_args() -> Result<u8, pico_args::Error> {
-    let mut args = pico_args::Arguments::from_env();
-    args.free_from_str()
-}
-
-pub fn parse_exec_time(output: &str) -> f64 {
-    output.lines().fold(0_f64, |acc, l| {
-        if !l.contains("elapsed:") {
-            acc
-        } else {
-            let timing = l.split("(elapsed: ").last().unwrap();
-            // use `contains` istd. of `ends_with`: string may contain ANSI escape sequences.
-            // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-            if timing.contains("ns)") {
-                acc // range below rounding precision.
-            } else if timing.contains("µs)") {
-                acc + parse_time(timing, "µs") / 1000_f64
-            } else if timing.contains("ms)") {
-                acc + parse_time(timing, "ms")
-            } else if timing.contains("s)") {
-                acc + parse_time(timing, "s") * 1000_f64
-            } else {
-                acc
+fn format_elapsed(elapsed: Duration, stats: Option<&Stats>) -> String {
+    match stats {
+        None => format!("{ANSI_ITALIC}(elapsed: {elapsed:.2?}){ANSI_RESET}"),
+        Some(Stats {
+            samples,
+            min,
+            median,
+            mean,
+            p95,
+            stddev,
+        }) => format!(
+            "{ANSI_ITALIC}(median: {median:.2?}, mean: {mean:.2?} ± {stddev:.2?}, min: {min:.2?}, p95: {p95:.2?}, samples: {samples}){ANSI_RESET}"
+        ),
+    }
+}
+
+fn format_check(check: &Check) -> String {
+    match check {
+        Check::Unpinned => String::new(),
+        Check::Correct => format!(" {}", check.symbol()),
+        Check::Wrong { expected } => format!(" {} expected {expected}", check.symbol()),
+    }
+}
+
+pub fn print_report(day: u8, report: &Report, answers: &Answers) {
+    println!("🎄 {ANSI_BOLD}Parse{ANSI_RESET} 🎄");
+    println!(
+        "{}",
+        format_elapsed(report.parse, report.parse_stats.as_ref())
+    );
+    for part in report.parts.iter() {
+        let check = format_check(&answers.check(day, part));
+        println!("🎄 {ANSI_BOLD}Part {}{ANSI_RESET} 🎄", part.part);
+        match &part.status {
+            Status::Solved(answer) => {
+                println!(
+                    "{answer}{check} {}",
+                    format_elapsed(part.elapsed, part.stats.as_ref())
+                );
+            }
+            Status::Unsolved => {
+                println!("not solved.{check}")
+            }
+            Status::Panicked(message) => {
+                println!("panicked: {message}{check}")
+            }
+            Status::Invalid(message) => {
+                println!("invalid input: {message}{check}")
+            }
+            Status::TimedOut => {
+                println!(
+                    "timed out.{check} {ANSI_ITALIC}(after {:.2?}){ANSI_RESET}",
+                    part.elapsed
+                )
             }
         }
-    })
+    }
 }
 
-/// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-#[cfg(test)]
-macro_rules! assert_approx_eq {
-    ($a:expr, $b:expr) => {{
-        let (a, b) = (&$a, &$b);
-        assert!(
-            (*a - *b).abs() < 1.0e-6,
-            "{} is not approximately equal to {}",
-            *a,
-            *b
-        );
+fn json_string(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len() + 2);
+    escaped.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+fn json_stats(stats: &Stats) -> String {
+    format!(
+        r#"{{"samples":{},"min_ns":{},"median_ns":{},"mean_ns":{},"p95_ns":{},"stddev_ns":{}}}"#,
+        stats.samples,
+        stats.min.as_nanos(),
+        stats.median.as_nanos(),
+        stats.mean.as_nanos(),
+        stats.p95.as_nanos(),
+        stats.stddev.as_nanos(),
+    )
+}
+
+/// One JSON object per part, newline delimited.
+pub fn json_records(day: u8, report: &Report, answers: &Answers) -> Vec<String> {
+    report
+        .parts
+        .iter()
+        .map(
+            |Part {
+                 part,
+                 status,
+                 elapsed,
+                 stats,
+             }| {
+                let check = answers.check(day, &report.parts[*part as usize - 1]);
+                let mut record = format!(
+                    r#"{{"day":{day},"part":{part},"answer":{},"parse_ns":{},"duration_ns":{},"status":"{}","check":"{}""#,
+                    status.answer().map_or("null".to_string(), json_string),
+                    report.parse.as_nanos(),
+                    elapsed.as_nanos(),
+                    status.name(),
+                    check.name(),
+                );
+                if let Status::Panicked(message) | Status::Invalid(message) = status {
+                    record += &format!(r#","message":{}"#, json_string(message));
+                }
+                if let Check::Wrong { expected } = &check {
+                    record += &format!(r#","expected":{}"#, json_string(expected));
+                }
+                if let Some(parse_stats) = &report.parse_stats {
+                    record += &format!(r#","parse_bench":{}"#, json_stats(parse_stats));
+                }
+                if let Some(stats) = stats {
+                    record += &format!(r#","bench":{}"#, json_stats(stats));
+                }
+                record + "}"
+            },
+        )
+        .collect()
+}
+
+pub fn print(day: u8, report: &Report, answers: &Answers, format: Format) {
+    match format {
+        Format::Text => print_report(day, report, answers),
+        Format::Json => {
+            for record in json_records(day, report, answers) {
+                println!("{record}");
+            }
+        }
+    }
+}
+
+/// Entry point of the standalone day binaries, honours the same options as `cargo solve`. The
+/// binary belongs to one year, so `--year` may only repeat it.
+pub fn solve<S: Solution>(year: u32, day: u8) {
+    let mut args = pico_args::Arguments::from_env();
+    let options = Options::parse(&mut args).unwrap_or_else(exit);
+    finish_args(args).unwrap_or_else(exit);
+    if let Some(other) = options.year.filter(|x| *x != year) {
+        return exit(Error::BadArgument(format!(
+            "This is the solution of day {day} of {year}, it can't run `--year {other}`."
+        )));
+    }
+    let answers = options.answers(year).unwrap_or_else(exit);
+
+    let input = options.read_input(year, day).unwrap_or_else(exit);
+    let report = match &options.bench {
+        Some(config) => bench::<S>(&input, &options.timeouts, config),
+        None => run::<S>(&input, &options.timeouts),
+    };
+    print(day, &report, &answers, options.format);
+    if answers.mismatches(day, &report) > 0 || report.failures() > 0 {
+        process::exit(1);
+    }
+}
+
+#[macro_export]
+macro_rules! solve {
+    ($year:expr, $day:expr, $solution:ty) => {{
+        aoc::solve::<$solution>($year, $day);
     }};
 }
 
+/// The crate root, so files are found no matter which directory a binary runs in.
+pub fn root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+}
+
+/// Year of files from before the multi-year layout, and of everything when `$YEAR` is not set.
+pub const DEFAULT_YEAR: u32 = 2022;
+
+/// `$YEAR` from `.env` or the environment, else [`DEFAULT_YEAR`].
+pub fn default_year() -> u32 {
+    dotenv::from_path(root().join(".env")).ok();
+    std::env::var("YEAR")
+        .ok()
+        .and_then(|x| x.parse().ok())
+        .unwrap_or(DEFAULT_YEAR)
+}
+
+/// `src/<folder>/<year>/<day>.txt`, a missing year falls back to [`default_year`].
+pub fn input_path(folder: &str, year: Option<u32>, day: u8) -> PathBuf {
+    root()
+        .join("src")
+        .join(folder)
+        .join(year.unwrap_or_else(default_year).to_string())
+        .join(format!("{day:02}.txt"))
+}
+
+/// Solution file of a day, it builds to the binary `<year>-<day>`.
+pub fn module_path(year: u32, day: u8) -> PathBuf {
+    root()
+        .join("src")
+        .join("bin")
+        .join(format!("{year}-{day:02}.rs"))
+}
+
+/// Reads an input or example file, see [`input_path`].
+pub fn read_file(folder: &str, year: Option<u32>, day: u8) -> Result<String> {
+    let path = input_path(folder, year, day);
+    fs::read_to_string(&path).map_err(|source| Error::MissingInput { path, source })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use helpers::parser;
 
     #[test]
-    fn test_parse_exec_time() {
-        assert_approx_eq!(
-            parse_exec_time(&format!(
-                "🎄 Part 1 🎄\n0 (elapsed: 74.13ns){ANSI_RESET}\n🎄 Part 2 🎄\n0 (elapsed: 50.00ns){ANSI_RESET}"
-            )),
-            0_f64
+    fn test_json_string() {
+        assert_eq!(json_string("2=-1=0"), r#""2=-1=0""#);
+        assert_eq!(json_string("a\"b\\c"), r#""a\"b\\c""#);
+        assert_eq!(json_string("██\n  "), r#""██\n  ""#);
+    }
+
+    #[test]
+    fn test_json_records() {
+        let mut report = Report {
+            parse: Duration::from_nanos(7),
+            parse_stats: None,
+            parts: [
+                Part::new(
+                    1,
+                    Status::Solved("24000".to_string()),
+                    Duration::from_nanos(74),
+                ),
+                Part::new(
+                    2,
+                    Status::Panicked("at the disco".to_string()),
+                    Duration::from_micros(1),
+                ),
+            ],
+        };
+        assert_eq!(
+            json_records(1, &report, &Answers::default()),
+            vec![
+                r#"{"day":1,"part":1,"answer":"24000","parse_ns":7,"duration_ns":74,"status":"solved","check":"unpinned"}"#,
+                r#"{"day":1,"part":2,"answer":null,"parse_ns":7,"duration_ns":1000,"status":"panicked","check":"unpinned","message":"at the disco"}"#,
+            ]
         );
 
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 755µs)\n🎄 Part 2 🎄\n0 (elapsed: 700µs)"),
-            1.455_f64
+        let answers = Answers::parse("[01]\npart_one = 24000\npart_two = 45000", 2022).unwrap();
+        assert_eq!(
+            json_records(1, &report, &answers),
+            vec![
+                r#"{"day":1,"part":1,"answer":"24000","parse_ns":7,"duration_ns":74,"status":"solved","check":"correct"}"#,
+                r#"{"day":1,"part":2,"answer":null,"parse_ns":7,"duration_ns":1000,"status":"panicked","check":"wrong","message":"at the disco","expected":"45000"}"#,
+            ]
         );
 
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 70µs)\n🎄 Part 2 🎄\n0 (elapsed: 1.45ms)"),
-            1.52_f64
+        report.parts[0].stats = Some(Stats::from_samples(&[Duration::from_nanos(70)]));
+        assert_eq!(
+            json_records(1, &report, &Answers::default())[0],
+            r#"{"day":1,"part":1,"answer":"24000","parse_ns":7,"duration_ns":74,"status":"solved","check":"unpinned","bench":{"samples":1,"min_ns":70,"median_ns":70,"mean_ns":70,"p95_ns":70,"stddev_ns":0}}"#
         );
+    }
 
-        assert_approx_eq!(
-            parse_exec_time(
-                "🎄 Part 1 🎄\n0 (elapsed: 10.3s)\n🎄 Part 2 🎄\n0 (elapsed: 100.50ms)"
-            ),
-            10400.50_f64
+    struct Numbers;
+
+    impl Solution for Numbers {
+        type Parsed = Vec<u32>;
+        type PartOne = u32;
+        type PartTwo = u32;
+
+        fn parse(input: &str) -> Self::Parsed {
+            parse_or_panic(Self::try_parse(input))
+        }
+        fn try_parse(input: &str) -> std::result::Result<Self::Parsed, ParseError> {
+            parser::parse_all(parser::lines(parser::number()), input)
+        }
+        fn part_one(numbers: &Self::Parsed) -> Option<u32> {
+            numbers.iter().max().copied()
+        }
+        fn part_two(numbers: &Self::Parsed) -> Option<u32> {
+            Some(numbers.iter().sum())
+        }
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let timeouts = Timeouts::default();
+        let report = run::<Numbers>("3\n4\n", &timeouts);
+        assert_eq!(
+            report.parts.each_ref().map(|x| x.status.answer()),
+            [Some("4"), Some("7")]
         );
+
+        let report = run::<Numbers>("3\nfour\n", &timeouts);
+        let status =
+            Status::Invalid("line 2, column 1: expected a number, found \"four\"".to_string());
+        assert!(report.parts.iter().all(|x| x.status == status));
+        assert_eq!(report.failures(), 2);
+        assert!(!Status::Unsolved.is_failure() && Status::TimedOut.is_failure());
+        assert_eq!(status.name(), "invalid");
+    }
+
+    #[test]
+    fn test_finish_args() {
+        let args = |x: &[&str]| pico_args::Arguments::from_vec(x.iter().map(Into::into).collect());
+        let mut known = args(&["--jobs", "4"]);
+        assert_eq!(known.opt_value_from_str("--jobs").unwrap(), Some(4));
+        assert!(finish_args(known).is_ok());
+
+        let mut misspelled = args(&["--jbos", "4", "--bench"]);
+        assert!(misspelled.contains("--bench"));
+        let error = finish_args(misspelled).unwrap_err();
+        assert_eq!(error.to_string(), "Unknown arguments `--jbos`, `4`.");
+        assert_eq!(error.exit_code(), 2);
     }
 }
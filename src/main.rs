@@ -2,42 +2,269 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use aoc::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-use std::process::Command;
+use aoc::answers::{self, Answers};
+use aoc::history::{self, History, Run};
+use aoc::{Day, Error, Format, Options, Report, Status, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use hashbrown::HashMap;
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
 
-fn main() {
-    let total: f64 = (1..=25)
-        .map(|day| {
-            let day = format!("{day:02}");
+mod days;
 
-            let cmd = Command::new("cargo")
-                .args(["run", "--release", "--bin", &day])
-                .output()
-                .unwrap();
+fn solve(day: u8, options: &Options, answers: &Answers) {
+    let year = options.year();
+    let Some(solution) = days::DAYS.iter().find(|x| x.year == year && x.day == day) else {
+        return aoc::exit(Error::BadArgument(format!(
+            "There is no solution for day {day} of {year}, create it with `cargo prepare {day} --year {year}`."
+        )));
+    };
 
-            println!("----------");
-            println!("{ANSI_BOLD}| Day {day} |{ANSI_RESET}");
-            println!("----------");
+    let input = options.read_input(year, day).unwrap_or_else(aoc::exit);
+    let report = options.run(solution, &input);
+    aoc::print(day, &report, answers, options.format);
+    if answers.mismatches(day, &report) > 0 || report.failures() > 0 {
+        process::exit(1);
+    }
+}
 
-            let output = String::from_utf8(cmd.stdout).unwrap();
-            let is_empty = output.is_empty();
+/// Runs every registered day of the year on `options.jobs` threads and hands the reports to `f`
+/// in day order. Days without an input file get `None`.
+fn run_days(options: &Options, mut f: impl FnMut(&Day, Option<Report>)) {
+    let year = options.year();
+    let days: Vec<&Day> = days::DAYS.iter().filter(|x| x.year == year).collect();
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
 
-            println!(
-                "{}",
-                if is_empty {
-                    "Not solved."
-                } else {
-                    output.trim()
+    thread::scope(|s| {
+        for _ in 0..options.jobs.clamp(1, days.len().max(1)) {
+            let sender = sender.clone();
+            let next = &next;
+            let days = &days;
+            s.spawn(move || loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let Some(day) = days.get(index) else {
+                    break;
+                };
+                let report = aoc::read_file("inputs", Some(day.year), day.day)
+                    .ok()
+                    .map(|input| options.run(day, &input));
+                if sender.send((index, report)).is_err() {
+                    break;
                 }
-            );
+            });
+        }
+        drop(sender);
+
+        // days finish out of order, hold them back until every earlier day is printed
+        let mut pending = HashMap::new();
+        let mut printed = 0;
+        for (index, report) in receiver {
+            pending.insert(index, report);
+            while let Some(report) = pending.remove(&printed) {
+                f(days[printed], report);
+                printed += 1;
+            }
+        }
+    });
+}
+
+/// Parts of `cargo all` grouped by how they ended.
+#[derive(Default)]
+struct Summary {
+    solved: Vec<String>,
+    unsolved: Vec<String>,
+    panicked: Vec<String>,
+    timed_out: Vec<String>,
+    invalid: Vec<String>,
+    no_input: Vec<String>,
+}
 
-            if is_empty {
-                0_f64
-            } else {
-                aoc::parse_exec_time(&output)
+impl Summary {
+    fn add(&mut self, day: u8, report: &Report) {
+        for part in report.parts.iter() {
+            let name = format!("{day:02}/{}", part.part);
+            match &part.status {
+                Status::Solved(_) => self.solved.push(name),
+                Status::Unsolved => self.unsolved.push(name),
+                Status::Panicked(message) => self.panicked.push(format!("{name} ({message})")),
+                Status::TimedOut => self.timed_out.push(name),
+                Status::Invalid(message) => self.invalid.push(format!("{name} ({message})")),
             }
-        })
-        .sum();
+        }
+    }
+
+    fn print(&self) {
+        for (category, parts) in [
+            ("Solved", &self.solved),
+            ("Returned None", &self.unsolved),
+            ("Panicked", &self.panicked),
+            ("Timed out", &self.timed_out),
+            ("Invalid input", &self.invalid),
+            ("No input", &self.no_input),
+        ] {
+            // solved parts are the boring majority, only count them
+            let list = match category {
+                "Solved" => String::new(),
+                _ if parts.is_empty() => String::new(),
+                _ => format!(" - {}", parts.join(", ")),
+            };
+            println!("{ANSI_BOLD}{category}:{ANSI_RESET} {}{list}", parts.len());
+        }
+    }
+}
+
+/// Saves a benchmarked run to the history and compares it with the previous one.
+fn record(run: Run, options: &Options) {
+    let mut history = match History::load() {
+        Ok(history) => history,
+        Err(e) => {
+            eprintln!("{e}, not recording this run.");
+            return;
+        }
+    };
+    let key = history::run_key(&run);
+
+    if options.format == Format::Text {
+        match history.baseline(&key, run.year) {
+            None => println!("No earlier run in `{}` to compare with.", history::FILE),
+            Some((baseline_key, baseline)) => {
+                let regressions = history::regressions(baseline, &run, options.threshold);
+                println!(
+                    "{ANSI_BOLD}Regressions:{ANSI_RESET} {} over {}% against {baseline_key}",
+                    regressions.len(),
+                    options.threshold
+                );
+                for x in regressions {
+                    println!(
+                        "⚠️  Day {:02} part {}: {:.2?} -> {:.2?} (+{:.0}%)",
+                        x.day,
+                        x.part,
+                        x.before,
+                        x.after,
+                        x.percent()
+                    );
+                }
+            }
+        }
+    }
+
+    history.insert(key, run);
+    if let Err(e) = history.save() {
+        eprintln!("{e}");
+    }
+}
+
+fn all(options: &Options, answers: &Answers) {
+    let started = Instant::now();
+    let mut mismatches = 0;
+    let mut failures = 0;
+    let mut total = Duration::ZERO;
+    let mut summary = Summary::default();
+    let mut run = Run::new(options.year());
+
+    run_days(options, |day, report| {
+        if options.format == Format::Text {
+            println!("----------");
+            println!("{ANSI_BOLD}| Day {:02} |{ANSI_RESET}", day.day);
+            println!("----------");
+        }
+
+        let Some(report) = report else {
+            summary.no_input.push(format!("{:02}", day.day));
+            match options.format {
+                Format::Text => println!("Not solved."),
+                // nothing to verify without an input
+                Format::Json => aoc::print(
+                    day.day,
+                    &Report::unsolved(),
+                    &Answers::default(),
+                    options.format,
+                ),
+            }
+            return;
+        };
+
+        aoc::print(day.day, &report, answers, options.format);
+        mismatches += answers.mismatches(day.day, &report);
+        failures += report.failures();
+        total += report.elapsed();
+        summary.add(day.day, &report);
+        run.add(day.day, &report);
+    });
+
+    if options.format == Format::Text {
+        println!("----------");
+        summary.print();
+        // the sum of each part's own time, as if the days ran one after another. With several
+        // jobs they overlap and it also counts time spent waiting for a core
+        println!(
+            "{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{:.2}ms summed, {:.2}ms wall clock ({} jobs){ANSI_RESET}",
+            total.as_secs_f64() * 1000_f64,
+            started.elapsed().as_secs_f64() * 1000_f64,
+            options.jobs.max(1)
+        );
+    }
+
+    if options.bench.is_some() {
+        record(run, options);
+    }
+
+    if mismatches > 0 {
+        eprintln!("❌ {mismatches} answer(s) differ from `{}`.", answers::FILE);
+    }
+    if failures > 0 {
+        eprintln!("💥 {failures} part(s) panicked, timed out or got invalid input.");
+    }
+    if mismatches > 0 || failures > 0 {
+        process::exit(1);
+    }
+}
+
+/// Runs all days and writes their times to the table in `README.md`.
+fn readme(options: &Options, answers: &Answers) {
+    let mut reports = Vec::new();
+    run_days(options, |day, report| {
+        if let Some(report) = report {
+            reports.push((day.day, report));
+        }
+    });
+
+    aoc::readme::update(&aoc::readme::table(options.year(), &reports, answers))
+        .unwrap_or_else(aoc::exit);
+    println!("Updated {} ({} days).", aoc::readme::FILE, reports.len());
+}
+
+fn main() {
+    let mut args = pico_args::Arguments::from_env();
+
+    let subcommand = args.subcommand().unwrap_or_else(|e| aoc::exit(e.into()));
+    let options = Options::parse(&mut args).unwrap_or_else(aoc::exit);
+
+    if options.input.is_some() && subcommand.as_deref() != Some("solve") {
+        aoc::exit(Error::BadArgument(
+            "`--input` only works with `cargo solve`.".to_string(),
+        ))
+    }
+    let day = match subcommand.as_deref() {
+        Some("solve") => Some(args.free_from_str().unwrap_or_else(|_| {
+            aoc::exit(Error::BadArgument(
+                "Need to specify a day (as integer). example: `cargo solve 7`".to_string(),
+            ))
+        })),
+        _ => None,
+    };
+    aoc::finish_args(args).unwrap_or_else(aoc::exit);
+    let answers = options.answers(options.year()).unwrap_or_else(aoc::exit);
 
-    println!("{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{total:.2}ms{ANSI_RESET}");
+    match (subcommand.as_deref(), day) {
+        (None | Some("all"), _) => all(&options, &answers),
+        (Some("readme"), _) => readme(&options, &answers),
+        (Some("solve"), Some(day)) => solve(day, &options, &answers),
+        (Some(x), _) => aoc::exit(Error::BadArgument(format!(
+            "Unknown command `{x}`, expected `solve`, `all` or `readme`."
+        ))),
+    }
 }
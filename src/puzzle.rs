@@ -0,0 +1,272 @@
+/*
+ * This file contains template code.
+ * Reads the puzzle page for `cargo download <day> --puzzle` and `--example`.
+ */
+use crate::client::URL;
+use lazy_static::lazy_static;
+use regex::Regex;
+
+lazy_static! {
+    static ref ARTICLE: Regex =
+        Regex::new(r#"(?s)<article class="day-desc">(.*?)</article>"#).unwrap();
+    static ref HREF: Regex = Regex::new(r#"href="([^"]*)""#).unwrap();
+    static ref EXAMPLE: Regex = Regex::new(r"(?s)<pre><code>(.*?)</code></pre>").unwrap();
+    static ref ANSWER: Regex =
+        Regex::new(r"(?s)<code><em>(.*?)</em></code>|<em><code>(.*?)</code></em>").unwrap();
+    static ref TAG: Regex = Regex::new(r"<[^>]*>").unwrap();
+}
+
+/// Contents of the `<article class="day-desc">` sections, part two is only there once part one is solved.
+pub fn articles(html: &str) -> Vec<&str> {
+    ARTICLE
+        .captures_iter(html)
+        .map(|x| x.get(1).unwrap().as_str())
+        .collect()
+}
+
+pub fn unescape(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&nbsp;", " ")
+        // last, so `&amp;lt;` stays `&lt;`
+        .replace("&amp;", "&")
+}
+
+fn text(html: &str) -> String {
+    unescape(&TAG.replace_all(html, ""))
+}
+
+/// Every `<pre><code>` block on the page as plain text, the example input is usually the first one.
+pub fn examples(html: &str) -> Vec<String> {
+    EXAMPLE.captures_iter(html).map(|x| text(&x[1])).collect()
+}
+
+/// Answer for the example, by convention the last highlighted snippet of the part's article.
+pub fn example_answer(article: &str) -> Option<String> {
+    ANSWER
+        .captures_iter(article)
+        .last()
+        .and_then(|x| x.get(1).or_else(|| x.get(2)))
+        .map(|x| text(x.as_str()))
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Token<'a> {
+    Open { name: &'a str, attributes: &'a str },
+    Close(&'a str),
+    Text(&'a str),
+}
+
+fn tokens(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut rest = html;
+    while !rest.is_empty() {
+        let Some(start) = rest.find('<') else {
+            tokens.push(Token::Text(rest));
+            break;
+        };
+        if start > 0 {
+            tokens.push(Token::Text(&rest[..start]));
+        }
+        let Some(end) = rest[start..].find('>') else {
+            tokens.push(Token::Text(&rest[start..]));
+            break;
+        };
+        let tag = rest[start + 1..start + end].trim_end_matches('/').trim();
+        rest = &rest[start + end + 1..];
+
+        if let Some(name) = tag.strip_prefix('/') {
+            tokens.push(Token::Close(name.trim()));
+        } else {
+            let (name, attributes) = tag.split_once(' ').unwrap_or((tag, ""));
+            tokens.push(Token::Open { name, attributes });
+            // void elements never get a closing tag
+            if name == "br" || name == "hr" || name == "img" {
+                tokens.push(Token::Close(name));
+            }
+        }
+    }
+    tokens
+}
+
+/// Wraps the converted content of a closed element.
+fn wrap(name: &str, attributes: &str, inner: String, in_pre: bool) -> String {
+    match name {
+        _ if in_pre && name != "pre" => inner,
+        "h2" => format!("## {}\n\n", inner.trim()),
+        "p" => format!("{}\n\n", inner.trim()),
+        "ul" | "ol" => format!("{}\n", inner.trim_start()),
+        "li" => format!("- {}\n", inner.trim()),
+        "pre" => format!("```\n{}\n```\n\n", inner.trim_end_matches('\n')),
+        "br" => "  \n".to_string(),
+        // `<code><em>x</em></code>` highlights the whole snippet
+        "code" => match inner.strip_prefix('*').and_then(|x| x.strip_suffix('*')) {
+            Some(x) if !x.contains('*') => format!("*`{x}`*"),
+            _ => format!("`{inner}`"),
+        },
+        "em" => format!("*{inner}*"),
+        "a" => {
+            let href = HREF
+                .captures(attributes)
+                .map_or("", |x| x.get(1).unwrap().as_str());
+            match href.strip_prefix('/') {
+                Some(path) => format!("[{inner}]({URL}/{path})"),
+                None => format!("[{inner}]({href})"),
+            }
+        }
+        _ => inner,
+    }
+}
+
+/// Converts an article to Markdown, covering the handful of elements AoC uses.
+pub fn to_markdown(article: &str) -> String {
+    // one buffer per open element, the bottom one collects the result
+    let mut stack: Vec<(&str, &str, String)> = vec![("", "", String::new())];
+    for token in tokens(article) {
+        match token {
+            Token::Open { name, attributes } => stack.push((name, attributes, String::new())),
+            Token::Close(name) => {
+                // ignore stray closing tags instead of unwinding the whole document
+                if stack.len() < 2 || stack.last().unwrap().0 != name {
+                    continue;
+                }
+                let (name, attributes, inner) = stack.pop().unwrap();
+                let in_pre = stack.iter().any(|x| x.0 == "pre");
+                let wrapped = wrap(name, attributes, inner, in_pre);
+                stack.last_mut().unwrap().2.push_str(&wrapped);
+            }
+            Token::Text(text) => {
+                let in_pre = stack.iter().any(|x| x.0 == "pre");
+                let text = unescape(text);
+                let buffer = &mut stack.last_mut().unwrap().2;
+                if in_pre {
+                    buffer.push_str(&text);
+                } else if buffer.is_empty() || buffer.ends_with('\n') {
+                    // whitespace between block elements
+                    buffer.push_str(text.replace('\n', " ").trim_start());
+                } else {
+                    buffer.push_str(&text.replace('\n', " "));
+                }
+            }
+        }
+    }
+    while stack.len() > 1 {
+        let (_, _, inner) = stack.pop().unwrap();
+        stack.last_mut().unwrap().2.push_str(&inner);
+    }
+
+    let markdown = stack.pop().unwrap().2;
+    let mut lines: Vec<&str> = Vec::new();
+    for line in markdown.lines() {
+        let line = match line.trim().is_empty() {
+            true => "",
+            false => line,
+        };
+        // no more than one blank line in a row
+        if !(line.is_empty() && lines.last().is_some_and(|x| x.is_empty())) {
+            lines.push(line);
+        }
+    }
+    lines.join("\n").trim().to_string() + "\n"
+}
+
+/// Markdown of all articles on the puzzle page.
+pub fn markdown(html: &str) -> Option<String> {
+    let articles = articles(html);
+    if articles.is_empty() {
+        return None;
+    }
+    Some(
+        articles
+            .into_iter()
+            .map(to_markdown)
+            .collect::<Vec<_>>()
+            .join("\n"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 1: Calorie Counting ---</h2><p>The Elves take turns writing down the number of <em>Calories</em> contained by the various meals, see <a href="/2022/day/1/input">your input</a> or <a href="https://en.wikipedia.org/wiki/Calorie" target="_blank">this</a>.</p>
+<p>For example, suppose the Elves finished writing their items' Calories:</p>
+<pre><code>1000
+2000
+
+<em>3000</em> &lt; 4000
+</code></pre>
+<ul>
+<li>The first Elf is carrying <code>6000</code> Calories.</li>
+<li>The third Elf is carrying <code><em>24000</em></code> Calories &amp; more.</li>
+</ul>
+</article>
+<p>Your puzzle answer was <code>69528</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Find the top <em>three</em> Elves.</p>
+</article>
+</main>"#;
+
+    #[test]
+    fn test_articles() {
+        let articles = articles(PAGE);
+        assert_eq!(articles.len(), 2);
+        assert!(articles[1].starts_with("<h2 id=\"part2\">"));
+        assert!(!articles[0].contains("Your puzzle answer"));
+    }
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(examples(PAGE), vec!["1000\n2000\n\n3000 < 4000\n"]);
+        let articles = articles(PAGE);
+        assert_eq!(example_answer(articles[0]), Some("24000".to_string()));
+        assert_eq!(example_answer(articles[1]), None);
+        assert_eq!(
+            example_answer("<p>a <code><em>1</em></code> b <em><code>CMZ</code></em></p>"),
+            Some("CMZ".to_string())
+        );
+    }
+
+    #[test]
+    fn test_unescape() {
+        assert_eq!(
+            unescape("&lt;a&gt; &amp;&amp; &quot;b&quot;"),
+            "<a> && \"b\""
+        );
+        assert_eq!(unescape("&amp;lt;"), "&lt;");
+    }
+
+    #[test]
+    fn test_markdown() {
+        assert_eq!(
+            markdown(PAGE).unwrap(),
+            [
+                "## --- Day 1: Calorie Counting ---",
+                "",
+                "The Elves take turns writing down the number of *Calories* contained by the various meals, see [your input](https://adventofcode.com/2022/day/1/input) or [this](https://en.wikipedia.org/wiki/Calorie).",
+                "",
+                "For example, suppose the Elves finished writing their items' Calories:",
+                "",
+                "```",
+                "1000",
+                "2000",
+                "",
+                "3000 < 4000",
+                "```",
+                "",
+                "- The first Elf is carrying `6000` Calories.",
+                "- The third Elf is carrying *`24000`* Calories & more.",
+                "",
+                "## --- Part Two ---",
+                "",
+                "Find the top *three* Elves.",
+                "",
+            ]
+            .join("\n")
+        );
+        assert_eq!(markdown("<main>Please log in</main>"), None);
+    }
+}
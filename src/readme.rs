@@ -0,0 +1,121 @@
+/*
+ * This file contains template code.
+ * Benchmark table that `cargo readme` writes between the markers in `README.md`.
+ */
+use crate::answers::{Answers, Check};
+use crate::{Error, Part, Report, Status};
+use std::fs;
+use std::time::Duration;
+
+pub const FILE: &str = "README.md";
+pub const MARKER: &str = "<!--- benchmarking table --->";
+
+fn cell(day: u8, part: &Part, answers: &Answers) -> String {
+    let symbol = match (&part.status, answers.check(day, part)) {
+        (_, Check::Wrong { .. }) => "❌",
+        (_, Check::Correct) => "✅",
+        (Status::Solved(_), Check::Unpinned) => "⭐",
+        (Status::Unsolved, _) => return "—".to_string(),
+        (Status::Panicked(_), _) => return "💥 panicked".to_string(),
+        (Status::TimedOut, _) => return "⏱️ timed out".to_string(),
+        (Status::Invalid(_), _) => return "🚫 invalid input".to_string(),
+    };
+    format!("{symbol} `{:.2?}`", part.time())
+}
+
+/// Table of every day that was run; ✅/❌ for pinned answers, ⭐ for solved but unpinned parts.
+pub fn table(year: u32, reports: &[(u8, Report)], answers: &Answers) -> String {
+    let mut lines = vec![
+        "## Benchmarks".to_string(),
+        String::new(),
+        "| Day | Part 1 | Part 2 | Total |".to_string(),
+        "| :---: | :---: | :---: | :---: |".to_string(),
+    ];
+    for (day, report) in reports {
+        lines.push(format!(
+            "| [Day {day}](./src/bin/{year}-{day:02}.rs) | {} | {} | `{:.2?}` |",
+            cell(*day, &report.parts[0], answers),
+            cell(*day, &report.parts[1], answers),
+            report.elapsed(),
+        ));
+    }
+    let total: Duration = reports.iter().map(|(_, x)| x.elapsed()).sum();
+    lines.push(String::new());
+    lines.push(format!(
+        "**Total: {:.2}ms**",
+        total.as_secs_f64() * 1000_f64
+    ));
+    lines.join("\n")
+}
+
+/// Replaces whatever is between the two markers in `readme` with `section`.
+pub fn replace_section(readme: &str, section: &str) -> Result<String, String> {
+    let missing = || format!("{FILE} needs two `{MARKER}` lines to put the table between");
+    let start = readme.find(MARKER).ok_or_else(missing)? + MARKER.len();
+    let end = start + readme[start..].find(MARKER).ok_or_else(missing)?;
+    Ok(format!(
+        "{}\n{section}\n{}",
+        &readme[..start],
+        &readme[end..]
+    ))
+}
+
+/// Rewrites the table in the crate's `README.md`.
+pub fn update(section: &str) -> crate::Result<()> {
+    let path = crate::root().join(FILE);
+    let readme = fs::read_to_string(&path).map_err(|e| Error::io(&path, e))?;
+    let readme = replace_section(&readme, section).map_err(|message| Error::Malformed {
+        path: path.clone(),
+        message,
+    })?;
+    fs::write(&path, readme).map_err(|e| Error::io(path, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_replace_section() {
+        let readme = format!("# AoC\n{MARKER}\nold\ntable\n{MARKER}\n\n### Cli\n");
+        let replaced = replace_section(&readme, "new").unwrap();
+        assert_eq!(
+            replaced,
+            format!("# AoC\n{MARKER}\nnew\n{MARKER}\n\n### Cli\n")
+        );
+        assert_eq!(replace_section(&replaced, "new").unwrap(), replaced);
+
+        assert!(replace_section("# AoC\n", "new").is_err());
+        assert!(replace_section(&format!("# AoC\n{MARKER}\n"), "new").is_err());
+    }
+
+    #[test]
+    fn test_table() {
+        let answers = Answers::parse("[01]\npart_one = 24000", 2022).unwrap();
+        let report = Report {
+            parse: Duration::from_micros(10),
+            parse_stats: None,
+            parts: [
+                Part::new(
+                    1,
+                    Status::Solved("24000".to_string()),
+                    Duration::from_micros(20),
+                ),
+                Part::new(2, Status::Unsolved, Duration::from_micros(30)),
+            ],
+        };
+        assert_eq!(
+            table(2022, &[(1, report)], &answers),
+            [
+                "## Benchmarks",
+                "",
+                "| Day | Part 1 | Part 2 | Total |",
+                "| :---: | :---: | :---: | :---: |",
+                "| [Day 1](./src/bin/2022-01.rs) | ✅ `20.00µs` | — | `60.00µs` |",
+                "",
+                "**Total: 0.06ms**",
+            ]
+            .join("\n")
+        );
+    }
+}
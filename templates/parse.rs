@@ -0,0 +1,52 @@
+// https://adventofcode.com/{{YEAR}}/day/{{DAY}}
+use aoc::Solution;
+use lazy_static::lazy_static;
+use regex::Regex;
+
+lazy_static! {
+    static ref NUMBER: Regex = Regex::new(r"-?\d+").unwrap();
+}
+
+pub struct Day{{DAY_PADDED}};
+
+impl Solution for Day{{DAY_PADDED}} {
+    /// the numbers on each line
+    type Parsed = Vec<Vec<i64>>;
+    type PartOne = {{TYPE}};
+    type PartTwo = {{TYPE}};
+
+    fn parse(input: &str) -> Self::Parsed {
+        input
+            .lines()
+            .map(|line| {
+                NUMBER
+                    .find_iter(line)
+                    .map(|x| x.as_str().parse().unwrap())
+                    .collect()
+            })
+            .collect()
+    }
+    fn part_one(input: &Self::Parsed) -> Option<{{TYPE}}> {
+        None
+    }
+    fn part_two(input: &Self::Parsed) -> Option<{{TYPE}}> {
+        None
+    }
+}
+fn main() {
+    aoc::solve!({{YEAR}}, {{DAY}}, Day{{DAY_PADDED}});
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_part_one() {
+        let input = aoc::read_file("test_inputs", Some({{YEAR}}), {{DAY}}).unwrap();
+        assert_eq!(Day{{DAY_PADDED}}::part_one(&Day{{DAY_PADDED}}::parse(&input)), None);
+    }
+    #[test]
+    fn test_part_two() {
+        let input = aoc::read_file("test_inputs", Some({{YEAR}}), {{DAY}}).unwrap();
+        assert_eq!(Day{{DAY_PADDED}}::part_two(&Day{{DAY_PADDED}}::parse(&input)), None);
+    }
+}
@@ -0,0 +1,37 @@
+// https://adventofcode.com/{{YEAR}}/day/{{DAY}}
+use aoc::Solution;
+
+pub struct Day{{DAY_PADDED}};
+
+impl Solution for Day{{DAY_PADDED}} {
+    type Parsed = String;
+    type PartOne = {{TYPE}};
+    type PartTwo = {{TYPE}};
+
+    fn parse(input: &str) -> Self::Parsed {
+        input.to_string()
+    }
+    fn part_one(input: &Self::Parsed) -> Option<{{TYPE}}> {
+        None
+    }
+    fn part_two(input: &Self::Parsed) -> Option<{{TYPE}}> {
+        None
+    }
+}
+fn main() {
+    aoc::solve!({{YEAR}}, {{DAY}}, Day{{DAY_PADDED}});
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_part_one() {
+        let input = aoc::read_file("test_inputs", Some({{YEAR}}), {{DAY}}).unwrap();
+        assert_eq!(Day{{DAY_PADDED}}::part_one(&Day{{DAY_PADDED}}::parse(&input)), None);
+    }
+    #[test]
+    fn test_part_two() {
+        let input = aoc::read_file("test_inputs", Some({{YEAR}}), {{DAY}}).unwrap();
+        assert_eq!(Day{{DAY_PADDED}}::part_two(&Day{{DAY_PADDED}}::parse(&input)), None);
+    }
+}